@@ -0,0 +1,126 @@
+//! Builds the merkle tree for a merkle distribution, see SetMerkleRoot.
+//!
+//! Usage: merkle-tool <snapshot.json> <registrar> <snapshot_ts> <weight_ts> <total_amount>
+//!
+//! The snapshot is a `getProgramAccounts` JSON-RPC response for the
+//! voter-stake-registry program with base64 encoding. Each voter of the
+//! registrar gets a share of total_amount by their guaranteed locked vote
+//! weight at weight_ts, evaluated at snapshot_ts.
+//!
+//! Prints the root, the leaf count and every voter's claim with its proof as
+//! JSON. Hashes are hex encoded.
+
+use anchor_lang::prelude::Pubkey;
+use std::str::FromStr;
+use voter_stake_registry::state::{Registrar, Voter};
+
+mod snapshot;
+mod tree;
+
+use snapshot::*;
+use tree::*;
+
+fn hex(bytes: &[u8; 32]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Guaranteed locked vote weight of all deposits, like the program computes it
+/// without deposit filters, weight checkpoints or weight curve.
+fn locked_weight(
+    registrar: &Registrar,
+    voter: &Voter,
+    now_ts: i64,
+    weight_ts: i64,
+) -> Result<u64, String> {
+    let mut weight = 0u64;
+    for deposit in voter.deposits.iter().filter(|d| d.is_used) {
+        let mint_config = &registrar.voting_mints[deposit.voting_mint_config_idx as usize];
+        let max_locked_vote_weight = mint_config
+            .max_extra_lockup_vote_weight(deposit.amount_initially_locked_native)
+            .map_err(|e| e.to_string())?;
+        weight = weight.saturating_add(
+            deposit
+                .voting_power_locked_guaranteed(
+                    now_ts,
+                    weight_ts,
+                    max_locked_vote_weight,
+                    mint_config.lockup_saturation_secs,
+                )
+                .map_err(|e| e.to_string())?,
+        );
+    }
+    Ok(weight)
+}
+
+fn run(args: &[String]) -> Result<serde_json::Value, String> {
+    if args.len() != 5 {
+        return Err(
+            "usage: merkle-tool <snapshot.json> <registrar> <snapshot_ts> <weight_ts> <total_amount>"
+                .to_string(),
+        );
+    }
+    let snapshot = std::fs::read_to_string(&args[0]).map_err(|e| e.to_string())?;
+    let snapshot: serde_json::Value = serde_json::from_str(&snapshot).map_err(|e| e.to_string())?;
+    let registrar_pubkey = Pubkey::from_str(&args[1]).map_err(|e| e.to_string())?;
+    let parse_int = |arg: &str| u64::from_str(arg).map_err(|e| e.to_string());
+    let snapshot_ts = parse_int(&args[2])? as i64;
+    let weight_ts = parse_int(&args[3])? as i64;
+    let total_amount = parse_int(&args[4])?;
+
+    let accounts = read_program_accounts(&snapshot)?;
+    let registrar: Registrar = accounts
+        .iter()
+        .find(|(pubkey, _)| *pubkey == registrar_pubkey)
+        .and_then(|(_, data)| load_account(data))
+        .ok_or("registrar not found in snapshot")?;
+
+    let mut weights = vec![];
+    for (pubkey, data) in accounts.iter() {
+        let voter: Voter = match load_account(data) {
+            Some(voter) => voter,
+            None => continue,
+        };
+        if voter.registrar != registrar_pubkey {
+            continue;
+        }
+        let weight = locked_weight(&registrar, &voter, snapshot_ts, weight_ts)
+            .map_err(|e| format!("voter {}: {}", pubkey, e))?;
+        weights.push((*pubkey, weight));
+    }
+
+    let claims = split_amount(&weights, total_amount);
+    if claims.is_empty() {
+        return Err("no voter has locked vote weight".to_string());
+    }
+    let tree = MerkleTree::new(&claims);
+
+    let claims_json: Vec<serde_json::Value> = claims
+        .iter()
+        .map(|claim| {
+            let proof: Vec<String> = tree.proof(claim.index as usize).iter().map(hex).collect();
+            serde_json::json!({
+                "index": claim.index,
+                "voter": claim.voter.to_string(),
+                "amount": claim.amount,
+                "proof": proof,
+            })
+        })
+        .collect();
+    Ok(serde_json::json!({
+        "root": hex(&tree.root()),
+        "leaf_count": claims.len(),
+        "total_amount": claims.iter().map(|claim| claim.amount).sum::<u64>(),
+        "claims": claims_json,
+    }))
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(output) => println!("{}", serde_json::to_string_pretty(&output).unwrap()),
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Discriminator;
+use std::str::FromStr;
+
+/// Reads the accounts from a `getProgramAccounts` JSON-RPC response for the
+/// voter-stake-registry program, requested with base64 encoding.
+///
+/// Accepts the full response or just its `result` array.
+pub fn read_program_accounts(json: &serde_json::Value) -> Result<Vec<(Pubkey, Vec<u8>)>, String> {
+    let accounts = json
+        .get("result")
+        .unwrap_or(json)
+        .as_array()
+        .ok_or("expected an array of accounts")?;
+    accounts
+        .iter()
+        .map(|account| {
+            let pubkey = account["pubkey"].as_str().ok_or("account without pubkey")?;
+            let pubkey = Pubkey::from_str(pubkey).map_err(|e| e.to_string())?;
+            let data = account["account"]["data"][0]
+                .as_str()
+                .ok_or("account data must be base64 encoded")?;
+            let data = base64::decode(data).map_err(|e| e.to_string())?;
+            Ok((pubkey, data))
+        })
+        .collect()
+}
+
+/// Reads a zero copy anchor account of type T, if the data is one.
+pub fn load_account<T: bytemuck::Pod + Discriminator>(data: &[u8]) -> Option<T> {
+    let size = std::mem::size_of::<T>();
+    if data.len() < 8 + size || data[..8] != T::discriminator() {
+        return None;
+    }
+    Some(bytemuck::pod_read_unaligned(&data[8..8 + size]))
+}
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::Pubkey;
+use distribute_by_locked_vote_weight::merkle::{leaf_hash, node_hash};
+
+/// A leaf of the merkle tree: the amount a voter can claim.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Claim {
+    pub index: u32,
+    pub voter: Pubkey,
+    pub amount: u64,
+}
+
+/// Splits `total_amount` among the voters by weight, rounding down.
+///
+/// Voters without weight or with an amount of zero are left out. The claims are
+/// sorted by voter and their index is their position.
+pub fn split_amount(weights: &[(Pubkey, u64)], total_amount: u64) -> Vec<Claim> {
+    let total_weight: u128 = weights.iter().map(|(_, weight)| *weight as u128).sum();
+    if total_weight == 0 {
+        return vec![];
+    }
+    let mut amounts: Vec<(Pubkey, u64)> = weights
+        .iter()
+        .map(|(voter, weight)| {
+            let amount = (total_amount as u128 * *weight as u128 / total_weight) as u64;
+            (*voter, amount)
+        })
+        .filter(|(_, amount)| *amount > 0)
+        .collect();
+    amounts.sort_by_key(|(voter, _)| *voter);
+    amounts
+        .into_iter()
+        .enumerate()
+        .map(|(index, (voter, amount))| Claim {
+            index: index as u32,
+            voter,
+            amount,
+        })
+        .collect()
+}
+
+/// All levels of a merkle tree, from the leaves up to the root.
+///
+/// Nodes without a sibling are carried up a level unchanged.
+pub struct MerkleTree {
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl MerkleTree {
+    /// Builds the tree for `claims`, which must be indexed by their position.
+    pub fn new(claims: &[Claim]) -> Self {
+        assert!(!claims.is_empty());
+        let leaves = claims
+            .iter()
+            .enumerate()
+            .map(|(i, claim)| {
+                assert_eq!(claim.index as usize, i);
+                leaf_hash(claim.index, &claim.voter, claim.amount)
+            })
+            .collect();
+        let mut levels: Vec<Vec<[u8; 32]>> = vec![leaves];
+        while levels.last().unwrap().len() > 1 {
+            let level = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => node_hash(a, b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(level);
+        }
+        Self { levels }
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.levels.last().unwrap()[0]
+    }
+
+    /// The proof for the leaf at `index`.
+    pub fn proof(&self, index: usize) -> Vec<[u8; 32]> {
+        let mut proof = vec![];
+        let mut i = index;
+        for level in &self.levels[..self.levels.len() - 1] {
+            if let Some(sibling) = level.get(i ^ 1) {
+                proof.push(*sibling);
+            }
+            i /= 2;
+        }
+        proof
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use distribute_by_locked_vote_weight::merkle::verify;
+
+    fn claims(count: usize) -> Vec<Claim> {
+        (0..count)
+            .map(|i| Claim {
+                index: i as u32,
+                voter: Pubkey::new_unique(),
+                amount: 1 + i as u64,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn proofs_verify_for_all_tree_sizes() {
+        for count in 1..40 {
+            let claims = claims(count);
+            let tree = MerkleTree::new(&claims);
+            for claim in claims.iter() {
+                let leaf = leaf_hash(claim.index, &claim.voter, claim.amount);
+                let proof = tree.proof(claim.index as usize);
+                assert!(verify(&proof, &tree.root(), leaf), "{} leaves", count);
+
+                let wrong_leaf = leaf_hash(claim.index, &claim.voter, claim.amount + 1);
+                assert!(!verify(&proof, &tree.root(), wrong_leaf));
+            }
+        }
+    }
+
+    #[test]
+    fn split_amount_by_weight() {
+        let voters: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let weights = vec![
+            (voters[0], 300),
+            (voters[1], 100),
+            (voters[2], 0),
+            (voters[3], 1),
+        ];
+        let claims = split_amount(&weights, 100);
+
+        // no weight or rounded down to zero
+        assert_eq!(claims.len(), 2);
+        assert!(claims.iter().map(|c| c.amount).sum::<u64>() <= 100);
+        let amount_of = |voter: Pubkey| claims.iter().find(|c| c.voter == voter).unwrap().amount;
+        assert_eq!(amount_of(voters[0]), 74);
+        assert_eq!(amount_of(voters[1]), 24);
+        for (i, claim) in claims.iter().enumerate() {
+            assert_eq!(claim.index as usize, i);
+        }
+        assert!(claims[0].voter < claims[1].voter);
+
+        assert!(split_amount(&[(voters[0], 0)], 1000).is_empty());
+    }
+}
@@ -29,4 +29,114 @@ pub enum ErrorKind {
     // 6008 / 0x1778
     #[msg("weight timestamp must not lie within registration phase")]
     WeightNotDuringRegistration,
+    // 6009 / 0x1779
+    #[msg("the claim phase has ended")]
+    ClaimPhaseEnded,
+    // 6010 / 0x177a
+    #[msg("claim end timestamp must lie after the registration phase")]
+    ClaimEndDuringRegistration,
+    // 6011 / 0x177b
+    #[msg("the distribution has no claim end timestamp")]
+    NoClaimDeadline,
+    // 6012 / 0x177c
+    #[msg("the claim phase has not ended yet")]
+    ClaimPhaseNotEnded,
+    // 6013 / 0x177d
+    #[msg("not all participants have claimed yet")]
+    NotAllParticipantsClaimed,
+    // 6014 / 0x177e
+    #[msg("the distribution has been cancelled")]
+    DistributionCancelled,
+    // 6015 / 0x177f
+    #[msg("participants can only be closed during registration or after cancellation")]
+    TooLateToCloseParticipant,
+    // 6016 / 0x1780
+    #[msg("the participant has already claimed")]
+    AlreadyClaimed,
+    // 6017 / 0x1781
+    #[msg("the participant has not claimed yet")]
+    NotClaimed,
+    // 6018 / 0x1782
+    #[msg("the account is not a distribution or participant of the first program version")]
+    NotMigratable,
+    // 6019 / 0x1783
+    #[msg("the vesting schedule is invalid")]
+    InvalidVestingSchedule,
+    // 6020 / 0x1784
+    #[msg("the claim lockup configuration is invalid")]
+    InvalidClaimLockup,
+    // 6021 / 0x1785
+    #[msg("the distribution does not allow claiming into a lockup")]
+    ClaimLockupDisabled,
+    // 6022 / 0x1786
+    #[msg("the distribution requires claiming into a lockup")]
+    ClaimLockupRequired,
+    // 6023 / 0x1787
+    #[msg("the remaining accounts don't match the distribution's reward vaults")]
+    InvalidRewardVaultAccounts,
+    // 6024 / 0x1788
+    #[msg("the distribution already has the maximum number of reward vaults")]
+    TooManyRewardVaults,
+    // 6025 / 0x1789
+    #[msg("the distribution can only be funded during the registration phase")]
+    FundingOnlyDuringRegistration,
+    // 6026 / 0x178a
+    #[msg("the weight cap is invalid")]
+    InvalidWeightCap,
+    // 6027 / 0x178b
+    #[msg("the weight policy can't change once participants have registered")]
+    ParticipantsAlreadyRegistered,
+    // 6028 / 0x178c
+    #[msg("the weight curve is invalid")]
+    InvalidWeightCurve,
+    // 6029 / 0x178d
+    #[msg("the voter's weight is below the distribution's minimum weight")]
+    WeightBelowMinimum,
+    // 6030 / 0x178e
+    #[msg("the deposit filter is invalid")]
+    InvalidDepositFilter,
+    // 6031 / 0x178f
+    #[msg("the voting mints must be used by the registrar")]
+    InvalidVotingMints,
+    // 6032 / 0x1790
+    #[msg("the weight checkpoints are invalid")]
+    InvalidWeightCheckpoints,
+    // 6033 / 0x1791
+    #[msg("epoch distributions don't support vesting, reward vaults or verification windows")]
+    NotSupportedByEpochDistributions,
+    // 6034 / 0x1792
+    #[msg("the merkle proof is invalid")]
+    InvalidMerkleProof,
+    // 6035 / 0x1793
+    #[msg(
+        "merkle distributions don't support registration, epochs, vesting, reward vaults, verification windows or claim lockups"
+    )]
+    NotSupportedByMerkleDistributions,
+    // 6036 / 0x1794
+    #[msg("the signer is neither the admin nor has the required role")]
+    Unauthorized,
+    // 6037 / 0x1795
+    #[msg("refunds by the canceller must go to token accounts owned by the admin")]
+    InvalidRefundTarget,
+    // 6038 / 0x1796
+    #[msg("the claim end timestamp can only be extended")]
+    ClaimEndMustNotMoveForward,
+    // 6039 / 0x1797
+    #[msg("the registration end timestamp can only be extended")]
+    RegistrationEndMustNotMoveForward,
+    // 6040 / 0x1798
+    #[msg("participants can only be challenged during the verification window")]
+    NotInVerificationPhase,
+    // 6041 / 0x1799
+    #[msg("the participant's registered weight does not exceed its recomputed weight")]
+    ParticipantWeightValid,
+    // 6042 / 0x179a
+    #[msg("claim end timestamp must lie after the verification window")]
+    ClaimEndDuringVerification,
+    // 6043 / 0x179b
+    #[msg("the participant's distribution must be closed")]
+    DistributionNotClosed,
+    // 6044 / 0x179c
+    #[msg("an absolute weight cap must not be below the minimum weight")]
+    WeightCapBelowMinWeight,
 }
@@ -9,9 +9,48 @@ pub struct Info {
     pub distribution_amount: u64,
     /// Can claims be made?
     pub in_claim_phase: bool,
+    /// Can participants be challenged?
+    pub in_verification_phase: bool,
+    /// Was the distribution cancelled?
+    pub cancelled: bool,
+    /// The current epoch of an epoch distribution, if it has started
+    pub current_epoch: Option<u32>,
 
     /// The voter's current weight (if registration/update still possible)
     pub usable_weight: Option<u64>,
+    /// The voter's current weight without the weight cap
+    pub uncapped_usable_weight: Option<u64>,
+    /// The voter's current locked vote weight from each allowed voting mint,
+    /// before the weight curve and cap (if registration/update still possible)
+    pub usable_weight_by_mint: Vec<MintAmount>,
     /// The weight the participant is registered with
     pub registered_weight: Option<u64>,
+    /// The weight the participant's claims are based on, which can be lower
+    /// than the registered weight with a basis points weight cap
+    pub claim_weight: Option<u64>,
+    /// The weight needed to register
+    pub min_weight: u64,
+
+    /// The balances of the additional reward vaults
+    pub reward_vault_amounts: Vec<MintAmount>,
+}
+
+/// An amount of tokens or weight associated with a mint
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MintAmount {
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted by FundDistribution. The distribution only keeps totals, these
+/// events are the record of who funded how much.
+#[event]
+#[derive(Debug)]
+pub struct Funded {
+    pub distribution: Pubkey,
+    pub funder: Pubkey,
+    /// The amount deposited by this funding
+    pub amount: u64,
+    /// The total amount deposited by FundDistribution so far
+    pub funded_amount: u64,
 }
@@ -0,0 +1,19 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Completes an admin transfer proposed with SetAdmin, making the signer the
+/// distribution's admin.
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(mut, has_one = pending_admin)]
+    pub distribution: AccountLoader<'info, Distribution>,
+    pub pending_admin: Signer<'info>,
+}
+
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let mut distribution = ctx.accounts.distribution.load_mut()?;
+    distribution.admin = distribution.pending_admin;
+    distribution.pending_admin = Pubkey::default();
+
+    Ok(())
+}
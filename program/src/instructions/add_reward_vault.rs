@@ -0,0 +1,74 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+/// Adds a vault for an additional reward token to the distribution.
+///
+/// Claims pay the participant's share of every reward vault alongside the
+/// main vault. Like the main vault, the new vault is the distribution's
+/// associated token account for `mint` and can be funded with a normal
+/// spl_token transfer.
+///
+/// Can only be called by the admin or funder during the registration phase.
+/// Each mint can only be added once, since the vault account must not exist yet.
+#[derive(Accounts)]
+pub struct AddRewardVault<'info> {
+    #[account(mut)]
+    pub distribution: AccountLoader<'info, Distribution>,
+    pub authority: Signer<'info>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        associated_token::authority = distribution,
+        associated_token::mint = mint,
+        payer = payer
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn add_reward_vault(ctx: Context<AddRewardVault>) -> Result<()> {
+    let mut distribution = ctx.accounts.distribution.load_mut()?;
+    require!(
+        distribution.is_funder(ctx.accounts.authority.key),
+        ErrorKind::Unauthorized
+    );
+    require!(
+        !distribution.is_cancelled(),
+        ErrorKind::DistributionCancelled
+    );
+    require!(
+        distribution.in_registration_phase(),
+        ErrorKind::ClaimPhaseAlreadyStarted
+    );
+    require!(
+        !distribution.is_epoch_distribution(),
+        ErrorKind::NotSupportedByEpochDistributions
+    );
+    require!(
+        !distribution.is_merkle_distribution(),
+        ErrorKind::NotSupportedByMerkleDistributions
+    );
+
+    let index = distribution.reward_vault_count as usize;
+    require!(index < MAX_REWARD_VAULTS, ErrorKind::TooManyRewardVaults);
+    distribution.reward_vaults[index] = RewardVault {
+        mint: ctx.accounts.mint.key(),
+        vault: ctx.accounts.vault.key(),
+        rewards: Rewards::default(),
+    };
+    distribution.reward_vault_count += 1;
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use super::reward_vaults::*;
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Cancels a distribution during the registration phase, refunding the vault
+/// balance to a target_token account.
+///
+/// Reward vaults are refunded as well. Pass `[reward_vault, target_token]`
+/// pairs for all of them as remaining accounts.
+///
+/// Can be called by the admin or the canceller. The canceller can only refund
+/// to token accounts owned by the admin.
+///
+/// Afterwards participants can no longer be created, updated or claimed for.
+/// Existing participants can recover their rent with CloseParticipant.
+#[derive(Accounts)]
+pub struct CancelDistribution<'info> {
+    #[account(
+        mut,
+        has_one = vault,
+    )]
+    pub distribution: AccountLoader<'info, Distribution>,
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub target_token: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CancelDistribution<'info> {
+    pub fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
+        let program = self.token_program.to_account_info();
+        let accounts = token::Transfer {
+            from: self.vault.to_account_info(),
+            to: self.target_token.to_account_info(),
+            authority: self.distribution.to_account_info(),
+        };
+        CpiContext::new(program, accounts)
+    }
+}
+
+pub fn cancel_distribution<'info>(
+    ctx: Context<'_, '_, '_, 'info, CancelDistribution<'info>>,
+) -> Result<()> {
+    {
+        let mut distribution = ctx.accounts.distribution.load_mut()?;
+        require!(
+            distribution.is_canceller(ctx.accounts.authority.key),
+            ErrorKind::Unauthorized
+        );
+        require!(
+            !distribution.is_cancelled(),
+            ErrorKind::DistributionCancelled
+        );
+        require!(
+            distribution.in_registration_phase(),
+            ErrorKind::ClaimPhaseAlreadyStarted
+        );
+        distribution.cancelled = 1;
+    }
+
+    let distribution = ctx.accounts.distribution.load()?;
+    let reward_vaults = reward_vault_accounts(&distribution, ctx.remaining_accounts)?;
+    if *ctx.accounts.authority.key != distribution.admin {
+        require!(
+            ctx.accounts.target_token.owner == distribution.admin,
+            ErrorKind::InvalidRefundTarget
+        );
+        for (_, target) in reward_vaults.iter() {
+            let target = Account::<TokenAccount>::try_from(target)?;
+            require!(
+                target.owner == distribution.admin,
+                ErrorKind::InvalidRefundTarget
+            );
+        }
+    }
+
+    token::transfer(
+        ctx.accounts
+            .transfer_ctx()
+            .with_signer(&[distribution_seeds!(distribution)]),
+        ctx.accounts.vault.amount,
+    )?;
+    for (vault, target) in reward_vaults {
+        transfer_from_reward_vault(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.distribution.to_account_info(),
+            &distribution,
+            &vault,
+            &target,
+            vault.amount,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use voter_stake_registry::state as vsr;
+
+/// Challenges a participant's registered weight during the verification
+/// window, see SetVerificationWindow.
+///
+/// Recomputes the weight from the participant's current voter account, like
+/// registering would. If it is lower than the registered weight, for example
+/// because a grant was clawed back, the participant's weight is reduced to it.
+/// Voters whose weight dropped below min_weight keep no weight. Anyone can
+/// call this.
+#[derive(Accounts)]
+pub struct ChallengeParticipant<'info> {
+    #[account(
+        mut,
+        has_one = registrar,
+    )]
+    pub distribution: AccountLoader<'info, Distribution>,
+
+    #[account(
+        mut,
+        has_one = distribution,
+        has_one = voter,
+    )]
+    pub participant: AccountLoader<'info, Participant>,
+
+    #[account(
+        has_one = registrar,
+    )]
+    pub voter: AccountLoader<'info, vsr::Voter>,
+    pub registrar: AccountLoader<'info, vsr::Registrar>,
+}
+
+pub fn challenge_participant(ctx: Context<ChallengeParticipant>) -> Result<()> {
+    let mut distribution = ctx.accounts.distribution.load_mut()?;
+    require!(
+        !distribution.is_cancelled(),
+        ErrorKind::DistributionCancelled
+    );
+    require!(
+        distribution.in_verification_phase(),
+        ErrorKind::NotInVerificationPhase
+    );
+
+    let voter = ctx.accounts.voter.load()?;
+    let registrar = ctx.accounts.registrar.load()?;
+    let mut weight = distribution.voter_weight(&registrar, &voter)?;
+    if weight < distribution.min_weight {
+        weight = 0;
+    }
+
+    let mut participant = ctx.accounts.participant.load_mut()?;
+    require!(
+        weight < distribution.participant_weight(&participant),
+        ErrorKind::ParticipantWeightValid
+    );
+    distribution.set_participant_weight(&mut participant, weight);
+
+    Ok(())
+}
@@ -1,12 +1,25 @@
+use super::reward_vaults::*;
 use crate::error::*;
 use crate::state::*;
 use anchor_lang::prelude::*;
+use anchor_lang::AccountsClose;
 use anchor_spl::token::{self, Token, TokenAccount};
 
 /// Claims a participants share of the distribution's vault by transfering
 /// it to a target_token account.
 ///
-/// This also closes the participant account.
+/// With vesting, only the newly vested part of the share is transferred and
+/// Claim can be called again later. The same goes for tokens deposited into
+/// the vault after earlier claims: they are split among all participants.
+///
+/// If the distribution has reward vaults, the share of each is transferred
+/// too. Pass `[reward_vault, target_token]` pairs for all of them as
+/// remaining accounts.
+///
+/// Once the full share is claimed, this closes the participant account, unless
+/// the distribution keeps participants as a record of the payout. Closed
+/// participants no longer count towards the total weight, later deposits are
+/// split among the others.
 #[derive(Accounts)]
 pub struct Claim<'info> {
     #[account(
@@ -20,7 +33,6 @@ pub struct Claim<'info> {
         has_one = distribution,
         has_one = voter_authority,
         has_one = payer,
-        close = payer,
     )]
     pub participant: AccountLoader<'info, Participant>,
 
@@ -50,37 +62,47 @@ impl<'info> Claim<'info> {
     }
 }
 
-pub fn claim(ctx: Context<Claim>) -> Result<()> {
-    {
+pub fn claim<'info>(ctx: Context<'_, '_, '_, 'info, Claim<'info>>) -> Result<()> {
+    let reward_vaults = {
+        let distribution = ctx.accounts.distribution.load()?;
+        reward_vault_accounts(&distribution, ctx.remaining_accounts)?
+    };
+
+    let amounts = {
         let mut distribution = ctx.accounts.distribution.load_mut()?;
-        require!(distribution.in_claim_phase(), ErrorKind::NotInClaimPhase);
-        // If this is the first Claim, take a snapshot of how many tokens
-        // are in the vault.
-        if distribution.claim_count == 0 {
-            distribution.total_amount_to_distribute = ctx.accounts.vault.amount;
-        }
-        distribution.claim_count = distribution.claim_count.checked_add(1).unwrap();
-    }
+        require!(
+            !distribution.claim_lockup_required(),
+            ErrorKind::ClaimLockupRequired
+        );
+        let mut participant = ctx.accounts.participant.load_mut()?;
+        let vault_amounts = vault_amounts(ctx.accounts.vault.amount, &reward_vaults);
+        distribution.claim(&mut participant, &vault_amounts)?
+    };
 
     let distribution = ctx.accounts.distribution.load()?;
-    let participant = ctx.accounts.participant.load()?;
-
-    // This rounds down, meaning not all tokens may be fully distributed.
-    let amount = u64::try_from(
-        (distribution.total_amount_to_distribute as u128)
-            .checked_mul(participant.weight as u128)
-            .unwrap()
-            .checked_div(distribution.participant_total_weight)
-            .unwrap(),
-    )
-    .unwrap();
-
     token::transfer(
         ctx.accounts
             .transfer_ctx()
             .with_signer(&[distribution_seeds!(distribution)]),
-        amount,
+        amounts[0],
     )?;
+    for ((vault, target), &amount) in reward_vaults.iter().zip(&amounts[1..]) {
+        transfer_from_reward_vault(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.distribution.to_account_info(),
+            &distribution,
+            vault,
+            target,
+            amount,
+        )?;
+    }
+
+    let fully_claimed = ctx.accounts.participant.load()?.is_claimed();
+    if fully_claimed && !distribution.keeps_participants() {
+        ctx.accounts
+            .participant
+            .close(ctx.accounts.payer.to_account_info())?;
+    }
 
     Ok(())
 }
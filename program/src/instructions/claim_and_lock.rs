@@ -0,0 +1,192 @@
+use super::reward_vaults::*;
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::AccountsClose;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use voter_stake_registry::cpi as vsr_cpi;
+use voter_stake_registry::program::VoterStakeRegistry;
+
+/// Claims a participant's share into a lockup on their voter account.
+///
+/// The first call creates a deposit entry with the lockup kind and periods
+/// configured by SetClaimLockup at `deposit_entry_index` (which must be
+/// unused) and deposits the share into it, instead of transferring it to a
+/// free token account. Later claims of the same participant, for example of
+/// vested parts, deposit into that entry and unlock with it. A new entry is
+/// only created if the voter's entry no longer holds an unexpired lockup of
+/// that kind.
+///
+/// Shares of the reward vaults can't be locked. They are transferred to
+/// target accounts passed as remaining accounts like for Claim.
+///
+/// The voter_authority pays for the voter's vault if it doesn't exist yet.
+/// Closes the participant account like Claim.
+#[derive(Accounts)]
+pub struct ClaimAndLock<'info> {
+    #[account(
+        mut,
+        has_one = registrar,
+        has_one = vault,
+        has_one = mint,
+    )]
+    pub distribution: AccountLoader<'info, Distribution>,
+
+    #[account(
+        mut,
+        has_one = distribution,
+        has_one = voter,
+        has_one = voter_authority,
+        has_one = payer,
+    )]
+    pub participant: AccountLoader<'info, Participant>,
+
+    #[account(mut)]
+    pub vault: Box<Account<'info, TokenAccount>>,
+    pub mint: Box<Account<'info, Mint>>,
+
+    pub registrar: AccountLoader<'info, vsr::Registrar>,
+    // checked by voter-stake-registry
+    #[account(mut)]
+    pub voter: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub voter_vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub voter_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    pub vsr_program: Program<'info, VoterStakeRegistry>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> ClaimAndLock<'info> {
+    /// Whether the voter's deposit entry at `index` still holds a lockup of
+    /// `lockup_kind` in the distribution's mint that can take more tokens.
+    pub fn deposit_entry_reusable(&self, index: u8, lockup_kind: vsr::LockupKind) -> Result<bool> {
+        let registrar = self.registrar.load()?;
+        let voter_account = self.voter.to_account_info();
+        let voter_loader = AccountLoader::<vsr::Voter>::try_from(&voter_account)?;
+        let voter = voter_loader.load()?;
+        let deposit = &voter.deposits[index as usize];
+        let mint_index = registrar
+            .voting_mint_config_index(self.mint.key())
+            .map_err(vsr_error)?;
+        Ok(deposit.is_used
+            && deposit.voting_mint_config_idx as usize == mint_index
+            && deposit.lockup.kind == lockup_kind
+            && deposit
+                .lockup
+                .seconds_left(registrar.clock_unix_timestamp())
+                > 0)
+    }
+
+    pub fn create_deposit_entry_ctx(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, vsr_cpi::accounts::CreateDepositEntry<'info>> {
+        let program = self.vsr_program.to_account_info();
+        let accounts = vsr_cpi::accounts::CreateDepositEntry {
+            registrar: self.registrar.to_account_info(),
+            voter: self.voter.to_account_info(),
+            vault: self.voter_vault.to_account_info(),
+            voter_authority: self.voter_authority.to_account_info(),
+            payer: self.voter_authority.to_account_info(),
+            deposit_mint: self.mint.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            rent: self.rent.to_account_info(),
+        };
+        CpiContext::new(program, accounts)
+    }
+
+    pub fn deposit_ctx(&self) -> CpiContext<'_, '_, '_, 'info, vsr_cpi::accounts::Deposit<'info>> {
+        let program = self.vsr_program.to_account_info();
+        let accounts = vsr_cpi::accounts::Deposit {
+            registrar: self.registrar.to_account_info(),
+            voter: self.voter.to_account_info(),
+            vault: self.voter_vault.to_account_info(),
+            deposit_token: self.vault.to_account_info(),
+            deposit_authority: self.distribution.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        };
+        CpiContext::new(program, accounts)
+    }
+}
+
+pub fn claim_and_lock<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimAndLock<'info>>,
+    deposit_entry_index: u8,
+) -> Result<()> {
+    let reward_vaults = {
+        let distribution = ctx.accounts.distribution.load()?;
+        reward_vault_accounts(&distribution, ctx.remaining_accounts)?
+    };
+
+    let (amounts, lockup_kind) = {
+        let mut distribution = ctx.accounts.distribution.load_mut()?;
+        let lockup_kind = distribution
+            .claim_lockup_kind()
+            .ok_or(ErrorKind::ClaimLockupDisabled)?;
+        let mut participant = ctx.accounts.participant.load_mut()?;
+        let vault_amounts = vault_amounts(ctx.accounts.vault.amount, &reward_vaults);
+        let amounts = distribution.claim(&mut participant, &vault_amounts)?;
+        (amounts, lockup_kind)
+    };
+
+    let distribution = ctx.accounts.distribution.load()?;
+
+    let earlier_entry = ctx.accounts.participant.load()?.lockup_deposit_entry();
+    let reusable_entry = match earlier_entry {
+        Some(index) if ctx.accounts.deposit_entry_reusable(index, lockup_kind)? => Some(index),
+        _ => None,
+    };
+    let deposit_entry_index = match reusable_entry {
+        Some(index) => index,
+        None => {
+            vsr_cpi::create_deposit_entry(
+                ctx.accounts.create_deposit_entry_ctx(),
+                deposit_entry_index,
+                lockup_kind,
+                None,
+                distribution.claim_lockup_periods,
+                false,
+            )?;
+            let mut participant = ctx.accounts.participant.load_mut()?;
+            participant.set_lockup_deposit_entry(deposit_entry_index);
+            deposit_entry_index
+        }
+    };
+    vsr_cpi::deposit(
+        ctx.accounts
+            .deposit_ctx()
+            .with_signer(&[distribution_seeds!(distribution)]),
+        deposit_entry_index,
+        amounts[0],
+    )?;
+    for ((vault, target), &amount) in reward_vaults.iter().zip(&amounts[1..]) {
+        transfer_from_reward_vault(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.distribution.to_account_info(),
+            &distribution,
+            vault,
+            target,
+            amount,
+        )?;
+    }
+
+    let fully_claimed = ctx.accounts.participant.load()?.is_claimed();
+    if fully_claimed && !distribution.keeps_participants() {
+        ctx.accounts
+            .participant
+            .close(ctx.accounts.payer.to_account_info())?;
+    }
+
+    Ok(())
+}
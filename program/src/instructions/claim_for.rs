@@ -0,0 +1,132 @@
+use super::reward_vaults::*;
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::AccountsClose;
+use anchor_spl::associated_token::{get_associated_token_address, AssociatedToken};
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+/// Claims a participant's share on their behalf.
+///
+/// Like Claim, but doesn't need the voter_authority's signature: anyone can
+/// call it once the claim phase has started. The tokens always go to the
+/// voter_authority's associated token account, which is created at the
+/// cranker's expense if it doesn't exist yet.
+///
+/// Reward vaults are passed as remaining accounts like for Claim, but their
+/// targets must be existing associated token accounts of the voter_authority.
+///
+/// Like Claim, this closes the participant account unless the distribution
+/// keeps participants.
+#[derive(Accounts)]
+pub struct ClaimFor<'info> {
+    #[account(
+        mut,
+        has_one = vault,
+        has_one = mint,
+    )]
+    pub distribution: AccountLoader<'info, Distribution>,
+
+    #[account(
+        mut,
+        has_one = distribution,
+        has_one = voter_authority,
+        has_one = payer,
+    )]
+    pub participant: AccountLoader<'info, Participant>,
+
+    #[account(mut)]
+    pub vault: Box<Account<'info, TokenAccount>>,
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        associated_token::authority = voter_authority,
+        associated_token::mint = mint,
+        payer = cranker,
+    )]
+    pub target_token: Box<Account<'info, TokenAccount>>,
+
+    pub voter_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> ClaimFor<'info> {
+    pub fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
+        let program = self.token_program.to_account_info();
+        let accounts = token::Transfer {
+            from: self.vault.to_account_info(),
+            to: self.target_token.to_account_info(),
+            authority: self.distribution.to_account_info(),
+        };
+        CpiContext::new(program, accounts)
+    }
+}
+
+pub fn claim_for<'info>(ctx: Context<'_, '_, '_, 'info, ClaimFor<'info>>) -> Result<()> {
+    let reward_vaults = {
+        let distribution = ctx.accounts.distribution.load()?;
+        let reward_vaults = reward_vault_accounts(&distribution, ctx.remaining_accounts)?;
+        for (reward_vault, (_, target)) in
+            distribution.used_reward_vaults().iter().zip(&reward_vaults)
+        {
+            require!(
+                *target.key
+                    == get_associated_token_address(
+                        &ctx.accounts.voter_authority.key(),
+                        &reward_vault.mint
+                    ),
+                ErrorKind::InvalidRewardVaultAccounts
+            );
+        }
+        reward_vaults
+    };
+
+    let amounts = {
+        let mut distribution = ctx.accounts.distribution.load_mut()?;
+        require!(
+            !distribution.claim_lockup_required(),
+            ErrorKind::ClaimLockupRequired
+        );
+        let mut participant = ctx.accounts.participant.load_mut()?;
+        let vault_amounts = vault_amounts(ctx.accounts.vault.amount, &reward_vaults);
+        distribution.claim(&mut participant, &vault_amounts)?
+    };
+
+    let distribution = ctx.accounts.distribution.load()?;
+    token::transfer(
+        ctx.accounts
+            .transfer_ctx()
+            .with_signer(&[distribution_seeds!(distribution)]),
+        amounts[0],
+    )?;
+    for ((vault, target), &amount) in reward_vaults.iter().zip(&amounts[1..]) {
+        transfer_from_reward_vault(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.distribution.to_account_info(),
+            &distribution,
+            vault,
+            target,
+            amount,
+        )?;
+    }
+
+    let fully_claimed = ctx.accounts.participant.load()?.is_claimed();
+    if fully_claimed && !distribution.keeps_participants() {
+        ctx.accounts
+            .participant
+            .close(ctx.accounts.payer.to_account_info())?;
+    }
+
+    Ok(())
+}
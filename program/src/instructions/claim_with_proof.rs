@@ -0,0 +1,127 @@
+use crate::error::*;
+use crate::merkle;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+use std::mem::size_of;
+use voter_stake_registry::state as vsr;
+
+/// Claims a voter's amount from a merkle distribution by transfering it to a
+/// target_token account.
+///
+/// `proof` must show that `(index, voter, amount)` is a leaf of the
+/// distribution's merkle tree. Each leaf can only be claimed once, which is
+/// recorded in the ClaimBitmap covering `index`. The first claim in a bitmap's
+/// range creates it, funded by payer.
+///
+/// Only available in the claim phase.
+#[derive(Accounts)]
+#[instruction(index: u32)]
+pub struct ClaimWithProof<'info> {
+    #[account(
+        mut,
+        has_one = vault,
+        has_one = registrar,
+    )]
+    pub distribution: AccountLoader<'info, Distribution>,
+
+    #[account(
+        init_if_needed,
+        seeds = [
+            distribution.key().as_ref(),
+            b"claim_bitmap".as_ref(),
+            &ClaimBitmap::page_for(index).to_le_bytes(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + size_of::<ClaimBitmap>()
+    )]
+    pub claim_bitmap: AccountLoader<'info, ClaimBitmap>,
+
+    #[account(
+        has_one = registrar,
+        has_one = voter_authority,
+    )]
+    pub voter: AccountLoader<'info, vsr::Voter>,
+    pub registrar: AccountLoader<'info, vsr::Registrar>,
+    pub voter_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub target_token: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> ClaimWithProof<'info> {
+    pub fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
+        let program = self.token_program.to_account_info();
+        let accounts = token::Transfer {
+            from: self.vault.to_account_info(),
+            to: self.target_token.to_account_info(),
+            authority: self.distribution.to_account_info(),
+        };
+        CpiContext::new(program, accounts)
+    }
+}
+
+pub fn claim_with_proof(
+    ctx: Context<ClaimWithProof>,
+    index: u32,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    {
+        let mut distribution = ctx.accounts.distribution.load_mut()?;
+        require!(
+            !distribution.is_cancelled(),
+            ErrorKind::DistributionCancelled
+        );
+        require!(
+            !distribution.claim_phase_ended(),
+            ErrorKind::ClaimPhaseEnded
+        );
+        require!(distribution.in_claim_phase(), ErrorKind::NotInClaimPhase);
+        require!(
+            index < distribution.merkle_leaf_count,
+            ErrorKind::InvalidMerkleProof
+        );
+
+        let leaf = merkle::leaf_hash(index, &ctx.accounts.voter.key(), amount);
+        require!(
+            merkle::verify(&proof, &distribution.merkle_root, leaf),
+            ErrorKind::InvalidMerkleProof
+        );
+
+        let mut claim_bitmap = match ctx.accounts.claim_bitmap.load_mut() {
+            Ok(claim_bitmap) => claim_bitmap,
+            Err(_) => {
+                let mut claim_bitmap = ctx.accounts.claim_bitmap.load_init()?;
+                claim_bitmap.distribution = ctx.accounts.distribution.key();
+                claim_bitmap.page = ClaimBitmap::page_for(index);
+                claim_bitmap
+            }
+        };
+        require!(!claim_bitmap.is_claimed(index), ErrorKind::AlreadyClaimed);
+        claim_bitmap.set_claimed(index);
+
+        distribution.rewards.record_claim(amount);
+    }
+
+    let distribution = ctx.accounts.distribution.load()?;
+    token::transfer(
+        ctx.accounts
+            .transfer_ctx()
+            .with_signer(&[distribution_seeds!(distribution)]),
+        amount,
+    )?;
+
+    Ok(())
+}
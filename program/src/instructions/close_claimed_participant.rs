@@ -0,0 +1,30 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Closes a participant that was kept around after claiming, returning its
+/// rent to the payer that funded it.
+///
+/// Only relevant for distributions with keep_participants. Doesn't need the
+/// distribution account, so it works even after CloseDistribution.
+#[derive(Accounts)]
+pub struct CloseClaimedParticipant<'info> {
+    #[account(
+        mut,
+        has_one = voter_authority,
+        has_one = payer,
+        close = payer,
+    )]
+    pub participant: AccountLoader<'info, Participant>,
+
+    pub voter_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+pub fn close_claimed_participant(ctx: Context<CloseClaimedParticipant>) -> Result<()> {
+    let participant = ctx.accounts.participant.load()?;
+    require!(participant.is_claimed(), ErrorKind::NotClaimed);
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use super::reward_vaults::*;
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Closes a distribution and its vault after all participants have claimed.
+///
+/// Every registered participant must have claimed or been closed first, so
+/// the only participants left afterwards are claimed ones kept by
+/// keep_participants, which CloseClaimedParticipant closes without the
+/// distribution. Participants of cancelled and epoch distributions never
+/// count as claimed, they must all be closed with CloseParticipant first.
+/// Merkle distributions can only be closed once the claim phase has ended.
+///
+/// Any dust left in the vault due to rounding is transferred to target_token,
+/// rent from the vault and distribution accounts goes to rent_receiver.
+///
+/// Reward vaults are swept and closed as well. Pass `[reward_vault,
+/// target_token]` pairs for all of them as remaining accounts.
+#[derive(Accounts)]
+pub struct CloseDistribution<'info> {
+    #[account(
+        mut,
+        has_one = admin,
+        has_one = vault,
+        close = rent_receiver,
+    )]
+    pub distribution: AccountLoader<'info, Distribution>,
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub target_token: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub rent_receiver: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CloseDistribution<'info> {
+    pub fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
+        let program = self.token_program.to_account_info();
+        let accounts = token::Transfer {
+            from: self.vault.to_account_info(),
+            to: self.target_token.to_account_info(),
+            authority: self.distribution.to_account_info(),
+        };
+        CpiContext::new(program, accounts)
+    }
+
+    pub fn close_vault_ctx(&self) -> CpiContext<'_, '_, '_, 'info, token::CloseAccount<'info>> {
+        let program = self.token_program.to_account_info();
+        let accounts = token::CloseAccount {
+            account: self.vault.to_account_info(),
+            destination: self.rent_receiver.to_account_info(),
+            authority: self.distribution.to_account_info(),
+        };
+        CpiContext::new(program, accounts)
+    }
+}
+
+pub fn close_distribution<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseDistribution<'info>>,
+) -> Result<()> {
+    let distribution = ctx.accounts.distribution.load()?;
+    require!(
+        !distribution.in_registration_phase() || distribution.is_cancelled(),
+        ErrorKind::NotInClaimPhase
+    );
+    require!(
+        distribution.claim_count == distribution.participant_count,
+        ErrorKind::NotAllParticipantsClaimed
+    );
+    // merkle claims aren't counted, they can be made until the claim phase ends
+    require!(
+        !distribution.is_merkle_distribution()
+            || distribution.claim_phase_ended()
+            || distribution.is_cancelled(),
+        ErrorKind::NotAllParticipantsClaimed
+    );
+
+    token::transfer(
+        ctx.accounts
+            .transfer_ctx()
+            .with_signer(&[distribution_seeds!(distribution)]),
+        ctx.accounts.vault.amount,
+    )?;
+    token::close_account(
+        ctx.accounts
+            .close_vault_ctx()
+            .with_signer(&[distribution_seeds!(distribution)]),
+    )?;
+    for (vault, target) in reward_vault_accounts(&distribution, ctx.remaining_accounts)? {
+        let program = ctx.accounts.token_program.to_account_info();
+        let distribution_account = ctx.accounts.distribution.to_account_info();
+        transfer_from_reward_vault(
+            &program,
+            &distribution_account,
+            &distribution,
+            &vault,
+            &target,
+            vault.amount,
+        )?;
+        close_reward_vault(
+            &program,
+            &distribution_account,
+            &distribution,
+            &vault,
+            &ctx.accounts.rent_receiver.to_account_info(),
+        )?;
+    }
+
+    Ok(())
+}
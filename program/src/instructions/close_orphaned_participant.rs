@@ -0,0 +1,36 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Closes a participant whose distribution no longer exists, returning its
+/// rent to the payer that funded it.
+///
+/// ReclaimRemainder closes the distribution while some participants may not
+/// have claimed, and CloseParticipant needs the distribution. This recovers
+/// their rent afterwards.
+#[derive(Accounts)]
+pub struct CloseOrphanedParticipant<'info> {
+    pub distribution: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = distribution,
+        has_one = voter_authority,
+        has_one = payer,
+        close = payer,
+    )]
+    pub participant: AccountLoader<'info, Participant>,
+
+    pub voter_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+pub fn close_orphaned_participant(ctx: Context<CloseOrphanedParticipant>) -> Result<()> {
+    require!(
+        ctx.accounts.distribution.data_is_empty(),
+        ErrorKind::DistributionNotClosed
+    );
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Closes a participant, returning its rent to the payer that funded it.
+///
+/// During the registration phase this withdraws the participant from the
+/// distribution, removing its weight. Participants of cancelled distributions
+/// and of epoch distributions can be closed at any time. In epoch
+/// distributions, the participant's share of the current epoch is then left
+/// for the next one.
+#[derive(Accounts)]
+pub struct CloseParticipant<'info> {
+    #[account(mut)]
+    pub distribution: AccountLoader<'info, Distribution>,
+
+    #[account(
+        mut,
+        has_one = distribution,
+        has_one = voter_authority,
+        has_one = payer,
+        close = payer,
+    )]
+    pub participant: AccountLoader<'info, Participant>,
+
+    pub voter_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+pub fn close_participant(ctx: Context<CloseParticipant>) -> Result<()> {
+    let mut distribution = ctx.accounts.distribution.load_mut()?;
+    require!(
+        distribution.in_registration_phase()
+            || distribution.is_epoch_distribution()
+            || distribution.is_cancelled(),
+        ErrorKind::TooLateToCloseParticipant
+    );
+
+    let mut participant = ctx.accounts.participant.load_mut()?;
+    distribution.set_participant_weight(&mut participant, 0);
+    distribution.participant_count = distribution.participant_count.checked_sub(1).unwrap();
+
+    Ok(())
+}
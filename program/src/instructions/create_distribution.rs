@@ -8,13 +8,26 @@ use voter_stake_registry::state as vsr;
 
 /// Create a new distribution
 ///
-/// After creation, the distribution's vault can be funded with tokens by
-/// doing a normal spl_token transfer to it.
+/// After creation, the distribution's vault can be funded with tokens with
+/// FundDistribution or by doing a normal spl_token transfer to it. Vaults for
+/// additional reward tokens can be added with AddRewardVault. SetEpochs turns
+/// it into a rolling distribution with several epochs, SetMerkleRoot into one
+/// with amounts that are computed off-chain. Which deposits count towards a
+/// voter's weight can be restricted with SetVotingMints and similar
+/// instructions until the first participant registers.
 ///
 /// `registration_end_ts`: Participants must register before this time.
 ///     when it is reached, Claim can be called.
 /// `weight_ts`: The time at which the locked token vote weight is evaluated.
 ///     Must be >= registration_end_ts.
+/// `claim_end_ts`: Claims must be made before this time. Afterwards the admin
+///     can use ReclaimRemainder to recover what is left in the vault.
+///     Must be > registration_end_ts, or zero for no claim deadline.
+/// `keep_participants`: If set, Claim doesn't close participant accounts but
+///     marks them as claimed, so they serve as an on-chain record of the
+///     payout. Their rent can be recovered with CloseClaimedParticipant.
+/// `min_weight`: Voters need at least this much weight to register, to keep
+///     out dust accounts whose share would round to zero. Zero for no minimum.
 #[derive(Accounts)]
 #[instruction(index: u64)]
 pub struct CreateDistribution<'info> {
@@ -54,11 +67,18 @@ pub fn create_distribution(
     index: u64,
     registration_end_ts: u64,
     weight_ts: u64,
+    claim_end_ts: u64,
+    keep_participants: bool,
+    min_weight: u64,
 ) -> Result<()> {
     require!(
         registration_end_ts <= weight_ts,
         ErrorKind::WeightNotDuringRegistration
     );
+    require!(
+        claim_end_ts == 0 || claim_end_ts > registration_end_ts,
+        ErrorKind::ClaimEndDuringRegistration
+    );
 
     let bump = Pubkey::find_program_address(
         &[
@@ -73,19 +93,57 @@ pub fn create_distribution(
     let mut distribution = ctx.accounts.distribution.load_init()?;
     *distribution = Distribution {
         admin: ctx.accounts.admin.key(),
+        creator: ctx.accounts.admin.key(),
+        pending_admin: Pubkey::default(),
+        funder: Pubkey::default(),
+        canceller: Pubkey::default(),
         registrar: ctx.accounts.registrar.key(),
         vault: ctx.accounts.vault.key(),
         mint: ctx.accounts.mint.key(),
+        merkle_root: [0; 32],
         index,
         bump,
         participant_total_weight: 0,
+        prior_participant_total_weight: 0,
+        max_weight_per_participant: 0,
+        min_weight,
+        min_lockup_end_ts: 0,
         registration_end_ts,
         weight_ts,
-        total_amount_to_distribute: 0,
+        claim_end_ts,
+        vesting_start_ts: 0,
+        vesting_cliff_ts: 0,
+        vesting_end_ts: 0,
+        rewards: Rewards::default(),
+        funded_amount: 0,
+        epoch_duration: 0,
+        epoch_amount: 0,
+        verification_duration: 0,
+        legacy_total_amount_to_distribute: 0,
         time_offset: 0,
         participant_count: 0,
         claim_count: 0,
-        reserved: [0; 39],
+        claim_lockup_periods: 0,
+        funding_call_count: 0,
+        weight_change_epoch: 0,
+        next_unstarted_epoch: 0,
+        merkle_leaf_count: 0,
+        weight_revision: 0,
+        closed_participant_weight: 0,
+        cancelled: 0,
+        keep_participants: keep_participants as u8,
+        claim_lockup_kind: 0,
+        claim_lockup_required: 0,
+        reward_vaults: [RewardVault::default(); MAX_REWARD_VAULTS],
+        reward_vault_count: 0,
+        funding_registration_only: 0,
+        max_weight_in_bps: 0,
+        weight_curve: 0,
+        deposit_lockup_kinds: 0,
+        voting_mints: 0,
+        weight_checkpoints: [WeightCheckpoint::default(); MAX_WEIGHT_CHECKPOINTS],
+        weight_checkpoint_count: 0,
+        reserved: [0; 12],
     };
 
     Ok(())
@@ -9,6 +9,9 @@ use voter_stake_registry::state as vsr;
 /// Having a participant account means that Claim can be called when the claim
 /// phase has started. Use UpdateParticipant if the voter's weight increases and
 /// you want to update the value stored in the participant account.
+///
+/// Epoch distributions allow registering at any time before the claim phase
+/// ends, the weight then counts from the next epoch on.
 #[derive(Accounts)]
 pub struct CreateParticipant<'info> {
     #[account(
@@ -42,14 +45,26 @@ pub struct CreateParticipant<'info> {
 pub fn create_participant(ctx: Context<CreateParticipant>) -> Result<()> {
     let mut distribution = ctx.accounts.distribution.load_mut()?;
     require!(
-        distribution.in_registration_phase(),
+        !distribution.is_cancelled(),
+        ErrorKind::DistributionCancelled
+    );
+    require!(
+        distribution.registration_open(),
         ErrorKind::TooLateToRegister
     );
+    require!(
+        !distribution.is_merkle_distribution(),
+        ErrorKind::NotSupportedByMerkleDistributions
+    );
 
     let voter = ctx.accounts.voter.load()?;
     let registrar = ctx.accounts.registrar.load()?;
     let weight = distribution.voter_weight(&registrar, &voter)?;
     require!(weight > 0, ErrorKind::NoLockedVoteWeight);
+    require!(
+        weight >= distribution.min_weight,
+        ErrorKind::WeightBelowMinimum
+    );
 
     let mut participant = ctx.accounts.participant.load_init()?;
     *participant = Participant {
@@ -57,12 +72,20 @@ pub fn create_participant(ctx: Context<CreateParticipant>) -> Result<()> {
         voter: ctx.accounts.voter.key(),
         voter_authority: voter.voter_authority,
         payer: ctx.accounts.payer.key(),
-        weight,
+        weight: 0,
+        prior_weight: 0,
+        rewards: ParticipantRewards::default(),
+        reward_vault_rewards: [ParticipantRewards::default(); MAX_REWARD_VAULTS],
+        claimed_ts: 0,
+        claimed: 0,
+        weight_change_epoch: 0,
+        next_claim_epoch: 0,
+        weight_revision: distribution.weight_revision,
+        lockup_deposit_entry_index: 0,
+        has_lockup_deposit_entry: 0,
+        reserved: [0; 9],
     };
-    distribution.participant_total_weight = distribution
-        .participant_total_weight
-        .checked_add(weight.into())
-        .unwrap();
+    distribution.set_participant_weight(&mut participant, weight);
     distribution.participant_count = distribution.participant_count.checked_add(1).unwrap();
 
     Ok(())
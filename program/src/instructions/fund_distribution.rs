@@ -0,0 +1,82 @@
+use crate::error::*;
+use crate::events::Funded;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Deposits tokens from a funder's token account into the distribution's vault.
+///
+/// Unlike a direct token transfer to the vault, this is recorded in the
+/// distribution's funded_amount and emits a Funded event. Possible until the
+/// claim phase ends, or only during registration if the admin restricted it
+/// with SetFundingRestriction.
+///
+/// Anyone can fund, unless SetRoles assigned a funder. Then only the funder and
+/// the admin can.
+#[derive(Accounts)]
+pub struct FundDistribution<'info> {
+    #[account(
+        mut,
+        has_one = vault,
+    )]
+    pub distribution: AccountLoader<'info, Distribution>,
+
+    #[account(mut)]
+    pub vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub funder_token: Box<Account<'info, TokenAccount>>,
+    pub funder: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> FundDistribution<'info> {
+    pub fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
+        let program = self.token_program.to_account_info();
+        let accounts = token::Transfer {
+            from: self.funder_token.to_account_info(),
+            to: self.vault.to_account_info(),
+            authority: self.funder.to_account_info(),
+        };
+        CpiContext::new(program, accounts)
+    }
+}
+
+pub fn fund_distribution(ctx: Context<FundDistribution>, amount: u64) -> Result<()> {
+    let funded_amount = {
+        let mut distribution = ctx.accounts.distribution.load_mut()?;
+        require!(
+            !distribution.is_cancelled(),
+            ErrorKind::DistributionCancelled
+        );
+        require!(
+            !distribution.claim_phase_ended(),
+            ErrorKind::ClaimPhaseEnded
+        );
+        require!(
+            distribution.funder == Pubkey::default()
+                || distribution.is_funder(&ctx.accounts.funder.key()),
+            ErrorKind::Unauthorized
+        );
+        require!(
+            !distribution.funding_registration_only() || distribution.in_registration_phase(),
+            ErrorKind::FundingOnlyDuringRegistration
+        );
+
+        distribution.funded_amount = distribution.funded_amount.checked_add(amount).unwrap();
+        distribution.funding_call_count = distribution.funding_call_count.checked_add(1).unwrap();
+        distribution.funded_amount
+    };
+
+    token::transfer(ctx.accounts.transfer_ctx(), amount)?;
+
+    emit!(Funded {
+        distribution: ctx.accounts.distribution.key(),
+        funder: ctx.accounts.funder.key(),
+        amount,
+        funded_amount,
+    });
+
+    Ok(())
+}
@@ -1,11 +1,14 @@
 use crate::error::*;
-use crate::events::Info;
+use crate::events::{Info, MintAmount};
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_spl::token::TokenAccount;
 use voter_stake_registry::state as vsr;
 
 /// Logs an Info event. Used for getting status information in uis.
+///
+/// Pass the distribution's reward vaults as remaining accounts to get their
+/// balances.
 #[derive(Accounts)]
 pub struct LogInfo<'info> {
     #[account(
@@ -30,28 +33,77 @@ pub struct LogInfo<'info> {
     pub registrar: AccountLoader<'info, vsr::Registrar>,
 }
 
-pub fn log_info(ctx: Context<LogInfo>) -> Result<()> {
+pub fn log_info<'info>(ctx: Context<'_, '_, '_, 'info, LogInfo<'info>>) -> Result<()> {
     let distribution = ctx.accounts.distribution.load()?;
     let in_claim_phase = distribution.in_claim_phase();
 
     let voter = ctx.accounts.voter.load()?;
     let registrar = ctx.accounts.registrar.load()?;
-    let usable_weight = if distribution.in_registration_phase() {
-        Some(distribution.voter_weight(&registrar, &voter)?)
-    } else {
-        None
-    };
+    let (usable_weight, uncapped_usable_weight, usable_weight_by_mint) =
+        if distribution.registration_open() {
+            let weights = distribution.locked_weight_by_voting_mint(&registrar, &voter)?;
+            let usable_weight_by_mint = registrar
+                .voting_mints
+                .iter()
+                .zip(weights)
+                .enumerate()
+                .filter(|(index, (config, _))| {
+                    config.mint != Pubkey::default() && distribution.voting_mint_allowed(*index)
+                })
+                .map(|(_, (config, weight))| MintAmount {
+                    mint: config.mint,
+                    amount: weight,
+                })
+                .collect();
+            (
+                Some(distribution.voter_weight(&registrar, &voter)?),
+                Some(distribution.uncapped_voter_weight(&registrar, &voter)?),
+                usable_weight_by_mint,
+            )
+        } else {
+            (None, None, vec![])
+        };
     let registered_weight =
         AccountLoader::<'_, Participant>::try_from(&ctx.accounts.participant.to_account_info())
-            .and_then(|l| l.load().map(|p| p.weight))
+            .and_then(|l| l.load().map(|p| distribution.participant_weight(&p)))
             .ok();
+    let claim_weight = registered_weight.map(|weight| distribution.claim_weight(weight));
+
+    let reward_vaults = distribution.used_reward_vaults();
+    require!(
+        ctx.remaining_accounts.len() == reward_vaults.len(),
+        ErrorKind::InvalidRewardVaultAccounts
+    );
+    let reward_vault_amounts = reward_vaults
+        .iter()
+        .zip(ctx.remaining_accounts)
+        .map(|(reward_vault, account)| {
+            require!(
+                *account.key == reward_vault.vault,
+                ErrorKind::InvalidRewardVaultAccounts
+            );
+            let vault = Account::<TokenAccount>::try_from(account)?;
+            Ok(MintAmount {
+                mint: reward_vault.mint,
+                amount: vault.amount,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
 
     emit!(Info {
         participant_total_weight: distribution.participant_total_weight,
         distribution_amount: ctx.accounts.vault.amount,
         in_claim_phase,
+        in_verification_phase: distribution.in_verification_phase(),
+        cancelled: distribution.is_cancelled(),
+        current_epoch: distribution.current_epoch(),
         usable_weight,
+        uncapped_usable_weight,
+        usable_weight_by_mint,
         registered_weight,
+        claim_weight,
+        min_weight: distribution.min_weight,
+        reward_vault_amounts,
     });
 
     Ok(())
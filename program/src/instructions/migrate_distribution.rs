@@ -0,0 +1,67 @@
+use super::migration::*;
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use std::mem::size_of;
+
+/// Migrates a distribution created by the first program version to the
+/// current layout. Anyone can call it, payer tops up the rent for the larger
+/// account.
+///
+/// The first version's fields keep their offsets, the new ones are zero, so
+/// the distribution behaves as if it had been created with default settings.
+/// The first version split the vault balance at the first claim among the
+/// participants. That snapshot becomes the start of the rewards accounting:
+/// whatever left the vault since then was claimed, and the remaining
+/// participants get the same share of it as before. The weight of
+/// participants that claimed before the migration isn't known, so their share
+/// of later deposits stays in the vault.
+///
+/// Other instructions fail on distributions that weren't migrated yet. Their
+/// participants need MigrateParticipant.
+#[derive(Accounts)]
+pub struct MigrateDistribution<'info> {
+    #[account(mut)]
+    pub distribution: UncheckedAccount<'info>,
+
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_distribution(ctx: Context<MigrateDistribution>) -> Result<()> {
+    let account = ctx.accounts.distribution.to_account_info();
+    require_legacy_account::<Distribution>(&account, LEGACY_DISTRIBUTION_SIZE)?;
+    grow_account(
+        &account,
+        8 + size_of::<Distribution>(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let loader = AccountLoader::<Distribution>::try_from(&account)?;
+    let mut distribution = loader.load_mut()?;
+    require!(
+        distribution.vault == ctx.accounts.vault.key(),
+        ErrorKind::NotMigratable
+    );
+
+    // the first version derived the address from the admin, which couldn't change
+    distribution.creator = distribution.admin;
+
+    if distribution.claim_count > 0 {
+        let snapshot_amount = distribution.legacy_total_amount_to_distribute;
+        let claimed_amount = snapshot_amount.saturating_sub(ctx.accounts.vault.amount);
+        let participant_total_weight = distribution.participant_total_weight;
+        distribution.rewards.record_claim(claimed_amount);
+        distribution
+            .rewards
+            .update(snapshot_amount - claimed_amount, participant_total_weight);
+    }
+
+    Ok(())
+}
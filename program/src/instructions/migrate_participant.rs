@@ -0,0 +1,34 @@
+use super::migration::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+/// Migrates a participant created by the first program version to the current
+/// layout. Anyone can call it, payer tops up the rent for the larger account.
+///
+/// The first version's fields keep their offsets and the new ones are zero,
+/// which is the state of a participant that hasn't claimed yet. The first
+/// version closed participants when they claimed, so all remaining ones are in
+/// that state. The topped up rent goes to the participant's payer when it is
+/// closed.
+#[derive(Accounts)]
+pub struct MigrateParticipant<'info> {
+    #[account(mut)]
+    pub participant: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_participant(ctx: Context<MigrateParticipant>) -> Result<()> {
+    let account = ctx.accounts.participant.to_account_info();
+    require_legacy_account::<Participant>(&account, LEGACY_PARTICIPANT_SIZE)?;
+    grow_account(
+        &account,
+        8 + size_of::<Participant>(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )
+}
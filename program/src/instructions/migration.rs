@@ -0,0 +1,40 @@
+use crate::error::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{program::invoke, system_instruction};
+use anchor_lang::Discriminator;
+
+/// Checks that `account` was created by the first program version: it must be
+/// owned by this program and consist of the discriminator of `T` followed by
+/// `legacy_size` bytes.
+pub fn require_legacy_account<T: Discriminator>(
+    account: &AccountInfo,
+    legacy_size: usize,
+) -> Result<()> {
+    require!(account.owner == &crate::id(), ErrorKind::NotMigratable);
+    let data = account.try_borrow_data()?;
+    require!(
+        data.len() == 8 + legacy_size && data[..8] == T::discriminator(),
+        ErrorKind::NotMigratable
+    );
+    Ok(())
+}
+
+/// Grows `account` to `new_len` bytes, zero-filling the new space. payer tops
+/// up the lamports needed to keep it rent exempt.
+pub fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    new_len: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(new_len);
+    let missing_lamports = rent_exempt_lamports.saturating_sub(account.lamports());
+    if missing_lamports > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, account.key, missing_lamports),
+            &[payer.clone(), account.clone(), system_program.clone()],
+        )?;
+    }
+    account.realloc(new_len, true)?;
+    Ok(())
+}
@@ -1,13 +1,79 @@
+mod accept_admin;
+mod add_reward_vault;
+mod cancel_distribution;
+mod challenge_participant;
 mod claim;
+mod claim_and_lock;
+mod claim_for;
+mod claim_with_proof;
+mod close_claimed_participant;
+mod close_distribution;
+mod close_orphaned_participant;
+mod close_participant;
 mod create_distribution;
 mod create_participant;
+mod fund_distribution;
 mod log_info;
+mod migrate_distribution;
+mod migrate_participant;
+mod migration;
+mod reclaim_remainder;
+mod refresh_participant;
+mod reward_vaults;
+mod set_admin;
+mod set_claim_end;
+mod set_claim_lockup;
+mod set_deposit_filter;
+mod set_epochs;
+mod set_funding_restriction;
+mod set_merkle_root;
+mod set_roles;
+#[cfg(any(feature = "localnet", feature = "test-bpf"))]
 mod set_time_offset;
+mod set_verification_window;
+mod set_vesting;
+mod set_voting_mints;
+mod set_weight_cap;
+mod set_weight_checkpoints;
+mod set_weight_curve;
+mod update_distribution_config;
 mod update_participant;
 
+pub use accept_admin::*;
+pub use add_reward_vault::*;
+pub use cancel_distribution::*;
+pub use challenge_participant::*;
 pub use claim::*;
+pub use claim_and_lock::*;
+pub use claim_for::*;
+pub use claim_with_proof::*;
+pub use close_claimed_participant::*;
+pub use close_distribution::*;
+pub use close_orphaned_participant::*;
+pub use close_participant::*;
 pub use create_distribution::*;
 pub use create_participant::*;
+pub use fund_distribution::*;
 pub use log_info::*;
+pub use migrate_distribution::*;
+pub use migrate_participant::*;
+pub use reclaim_remainder::*;
+pub use refresh_participant::*;
+pub use set_admin::*;
+pub use set_claim_end::*;
+pub use set_claim_lockup::*;
+pub use set_deposit_filter::*;
+pub use set_epochs::*;
+pub use set_funding_restriction::*;
+pub use set_merkle_root::*;
+pub use set_roles::*;
+#[cfg(any(feature = "localnet", feature = "test-bpf"))]
 pub use set_time_offset::*;
+pub use set_verification_window::*;
+pub use set_vesting::*;
+pub use set_voting_mints::*;
+pub use set_weight_cap::*;
+pub use set_weight_checkpoints::*;
+pub use set_weight_curve::*;
+pub use update_distribution_config::*;
 pub use update_participant::*;
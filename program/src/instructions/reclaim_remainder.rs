@@ -0,0 +1,104 @@
+use super::reward_vaults::*;
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Sweeps the remaining vault balance to a target_token account after the
+/// claim phase has ended.
+///
+/// This closes the vault and the distribution account, sending their rent to
+/// sol_destination. Only possible for distributions with a claim_end_ts.
+/// Participants who have not claimed by then lose their share, their rent can
+/// be recovered with CloseOrphanedParticipant.
+///
+/// Reward vaults are swept and closed as well. Pass `[reward_vault,
+/// target_token]` pairs for all of them as remaining accounts.
+#[derive(Accounts)]
+pub struct ReclaimRemainder<'info> {
+    #[account(
+        mut,
+        has_one = admin,
+        has_one = vault,
+        close = sol_destination,
+    )]
+    pub distribution: AccountLoader<'info, Distribution>,
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub target_token: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub sol_destination: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ReclaimRemainder<'info> {
+    pub fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
+        let program = self.token_program.to_account_info();
+        let accounts = token::Transfer {
+            from: self.vault.to_account_info(),
+            to: self.target_token.to_account_info(),
+            authority: self.distribution.to_account_info(),
+        };
+        CpiContext::new(program, accounts)
+    }
+
+    pub fn close_vault_ctx(&self) -> CpiContext<'_, '_, '_, 'info, token::CloseAccount<'info>> {
+        let program = self.token_program.to_account_info();
+        let accounts = token::CloseAccount {
+            account: self.vault.to_account_info(),
+            destination: self.sol_destination.to_account_info(),
+            authority: self.distribution.to_account_info(),
+        };
+        CpiContext::new(program, accounts)
+    }
+}
+
+pub fn reclaim_remainder<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReclaimRemainder<'info>>,
+) -> Result<()> {
+    let distribution = ctx.accounts.distribution.load()?;
+    require!(distribution.claim_end_ts != 0, ErrorKind::NoClaimDeadline);
+    require!(
+        distribution.claim_phase_ended(),
+        ErrorKind::ClaimPhaseNotEnded
+    );
+
+    token::transfer(
+        ctx.accounts
+            .transfer_ctx()
+            .with_signer(&[distribution_seeds!(distribution)]),
+        ctx.accounts.vault.amount,
+    )?;
+    token::close_account(
+        ctx.accounts
+            .close_vault_ctx()
+            .with_signer(&[distribution_seeds!(distribution)]),
+    )?;
+    for (vault, target) in reward_vault_accounts(&distribution, ctx.remaining_accounts)? {
+        let program = ctx.accounts.token_program.to_account_info();
+        let distribution_account = ctx.accounts.distribution.to_account_info();
+        transfer_from_reward_vault(
+            &program,
+            &distribution_account,
+            &distribution,
+            &vault,
+            &target,
+            vault.amount,
+        )?;
+        close_reward_vault(
+            &program,
+            &distribution_account,
+            &distribution,
+            &vault,
+            &ctx.accounts.sol_destination.to_account_info(),
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use voter_stake_registry::state as vsr;
+
+/// Recomputes a participant's weight from their current voter account.
+///
+/// Unlike UpdateParticipant, this also accepts decreases, which happen when
+/// locked tokens leave the voter, for example when a grant with allow_clawback
+/// is clawed back, or when the weight policy changed. Anyone can call it, so
+/// clawed-back tokens can't keep earning a share of the distribution, and so
+/// stale weights can be re-evaluated after weight_ts moved later.
+///
+/// Voters whose weight dropped below min_weight stay registered with zero
+/// weight. Possible whenever UpdateParticipant is, so not after
+/// registration_end_ts. Tokens clawed back later only lose their weight if the
+/// distribution has a verification window, see ChallengeParticipant.
+#[derive(Accounts)]
+pub struct RefreshParticipant<'info> {
+    #[account(
+        mut,
+        has_one = registrar,
+    )]
+    pub distribution: AccountLoader<'info, Distribution>,
+
+    #[account(
+        mut,
+        has_one = distribution,
+        has_one = voter,
+    )]
+    pub participant: AccountLoader<'info, Participant>,
+
+    #[account(
+        has_one = registrar,
+    )]
+    pub voter: AccountLoader<'info, vsr::Voter>,
+    pub registrar: AccountLoader<'info, vsr::Registrar>,
+}
+
+pub fn refresh_participant(ctx: Context<RefreshParticipant>) -> Result<()> {
+    let mut distribution = ctx.accounts.distribution.load_mut()?;
+    require!(
+        !distribution.is_cancelled(),
+        ErrorKind::DistributionCancelled
+    );
+    require!(
+        distribution.registration_open(),
+        ErrorKind::TooLateToRegister
+    );
+
+    let voter = ctx.accounts.voter.load()?;
+    let registrar = ctx.accounts.registrar.load()?;
+    let mut weight = distribution.voter_weight(&registrar, &voter)?;
+    if weight < distribution.min_weight {
+        weight = 0;
+    }
+
+    let mut participant = ctx.accounts.participant.load_mut()?;
+    distribution.set_participant_weight(&mut participant, weight);
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount};
+
+/// Reads the distribution's reward vaults from the remaining accounts.
+///
+/// Expects `[vault, target]` pairs in the order of the distribution's reward
+/// vaults. The target accounts are not checked, the token program ensures
+/// they have the right mint.
+pub fn reward_vault_accounts<'info>(
+    distribution: &Distribution,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<Vec<(Account<'info, TokenAccount>, AccountInfo<'info>)>> {
+    let reward_vaults = distribution.used_reward_vaults();
+    require!(
+        remaining_accounts.len() == 2 * reward_vaults.len(),
+        ErrorKind::InvalidRewardVaultAccounts
+    );
+    reward_vaults
+        .iter()
+        .zip(remaining_accounts.chunks(2))
+        .map(|(reward_vault, accounts)| {
+            require!(
+                *accounts[0].key == reward_vault.vault,
+                ErrorKind::InvalidRewardVaultAccounts
+            );
+            let vault = Account::<TokenAccount>::try_from(&accounts[0])?;
+            Ok((vault, accounts[1].clone()))
+        })
+        .collect()
+}
+
+/// The vault balance followed by the reward vault balances, as expected by
+/// Distribution::claim.
+pub fn vault_amounts(
+    vault_amount: u64,
+    reward_vaults: &[(Account<TokenAccount>, AccountInfo)],
+) -> Vec<u64> {
+    std::iter::once(vault_amount)
+        .chain(reward_vaults.iter().map(|(vault, _)| vault.amount))
+        .collect()
+}
+
+/// Transfers `amount` from a reward vault, signed by the distribution.
+pub fn transfer_from_reward_vault<'info>(
+    token_program: &AccountInfo<'info>,
+    distribution_account: &AccountInfo<'info>,
+    distribution: &Distribution,
+    vault: &Account<'info, TokenAccount>,
+    target: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let accounts = token::Transfer {
+        from: vault.to_account_info(),
+        to: target.clone(),
+        authority: distribution_account.clone(),
+    };
+    token::transfer(
+        CpiContext::new(token_program.clone(), accounts)
+            .with_signer(&[distribution_seeds!(distribution)]),
+        amount,
+    )
+}
+
+/// Closes an empty reward vault, signed by the distribution.
+pub fn close_reward_vault<'info>(
+    token_program: &AccountInfo<'info>,
+    distribution_account: &AccountInfo<'info>,
+    distribution: &Distribution,
+    vault: &Account<'info, TokenAccount>,
+    sol_destination: &AccountInfo<'info>,
+) -> Result<()> {
+    let accounts = token::CloseAccount {
+        account: vault.to_account_info(),
+        destination: sol_destination.clone(),
+        authority: distribution_account.clone(),
+    };
+    token::close_account(
+        CpiContext::new(token_program.clone(), accounts)
+            .with_signer(&[distribution_seeds!(distribution)]),
+    )
+}
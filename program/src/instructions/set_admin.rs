@@ -0,0 +1,24 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Proposes a new admin for the distribution.
+///
+/// The transfer completes when the new admin calls AcceptAdmin, so a typo
+/// can't lock the admin out. Until then the current admin stays in charge and
+/// can propose someone else. The distribution's address doesn't change, since
+/// it is derived from the admin at creation.
+///
+/// `new_admin`: The proposed admin. Zero to withdraw a pending proposal.
+#[derive(Accounts)]
+pub struct SetAdmin<'info> {
+    #[account(mut, has_one = admin)]
+    pub distribution: AccountLoader<'info, Distribution>,
+    pub admin: Signer<'info>,
+}
+
+pub fn set_admin(ctx: Context<SetAdmin>, new_admin: Pubkey) -> Result<()> {
+    let mut distribution = ctx.accounts.distribution.load_mut()?;
+    distribution.pending_admin = new_admin;
+
+    Ok(())
+}
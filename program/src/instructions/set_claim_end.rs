@@ -0,0 +1,40 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Extends the claim phase by postponing claim_end_ts.
+///
+/// Useful when a deadline approaches with many unclaimed shares, or when a
+/// governance proposal executes later than planned. Can't be called once the
+/// claim phase has ended, and never shortens it, since participants rely on
+/// the deadline they registered with.
+///
+/// `claim_end_ts`: The new claim deadline. Must be >= the current one, or zero
+///     to remove the deadline.
+#[derive(Accounts)]
+pub struct SetClaimEnd<'info> {
+    #[account(mut, has_one = admin)]
+    pub distribution: AccountLoader<'info, Distribution>,
+    pub admin: Signer<'info>,
+}
+
+pub fn set_claim_end(ctx: Context<SetClaimEnd>, claim_end_ts: u64) -> Result<()> {
+    let mut distribution = ctx.accounts.distribution.load_mut()?;
+    require!(
+        !distribution.is_cancelled(),
+        ErrorKind::DistributionCancelled
+    );
+    require!(
+        !distribution.claim_phase_ended(),
+        ErrorKind::ClaimPhaseEnded
+    );
+    require!(
+        distribution.claim_end_ts != 0
+            && (claim_end_ts == 0 || claim_end_ts >= distribution.claim_end_ts),
+        ErrorKind::ClaimEndMustNotMoveForward
+    );
+
+    distribution.claim_end_ts = claim_end_ts;
+
+    Ok(())
+}
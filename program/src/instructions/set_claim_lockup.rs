@@ -0,0 +1,59 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use voter_stake_registry::state as vsr;
+
+/// Configures claiming directly into a voter-stake-registry lockup.
+///
+/// Can only be called during the registration phase. Merkle distributions pay
+/// out with ClaimWithProof and don't support claim lockups.
+///
+/// `lockup_kind`: The vsr::LockupKind (as u8) of deposits created by
+///     ClaimAndLock. LockupKind::None disables ClaimAndLock.
+/// `lockup_periods`: The number of lockup periods (days or months, depending
+///     on the kind). Must be nonzero if enabled.
+/// `lockup_required`: If set, Claim and ClaimFor can't be used, participants
+///     must use ClaimAndLock.
+#[derive(Accounts)]
+pub struct SetClaimLockup<'info> {
+    #[account(mut, has_one = admin)]
+    pub distribution: AccountLoader<'info, Distribution>,
+    pub admin: Signer<'info>,
+}
+
+pub fn set_claim_lockup(
+    ctx: Context<SetClaimLockup>,
+    lockup_kind: u8,
+    lockup_periods: u32,
+    lockup_required: bool,
+) -> Result<()> {
+    let mut distribution = ctx.accounts.distribution.load_mut()?;
+    require!(
+        !distribution.is_cancelled(),
+        ErrorKind::DistributionCancelled
+    );
+    require!(
+        distribution.in_registration_phase(),
+        ErrorKind::ClaimPhaseAlreadyStarted
+    );
+    require!(
+        !distribution.is_merkle_distribution(),
+        ErrorKind::NotSupportedByMerkleDistributions
+    );
+
+    let kind = lockup_kind_from_u8(lockup_kind).ok_or(ErrorKind::InvalidClaimLockup)?;
+    if kind == vsr::LockupKind::None {
+        require!(
+            lockup_periods == 0 && !lockup_required,
+            ErrorKind::InvalidClaimLockup
+        );
+    } else {
+        require!(lockup_periods > 0, ErrorKind::InvalidClaimLockup);
+    }
+
+    distribution.claim_lockup_kind = lockup_kind;
+    distribution.claim_lockup_periods = lockup_periods;
+    distribution.claim_lockup_required = lockup_required as u8;
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use voter_stake_registry::state as vsr;
+
+/// Restricts which of a voter's deposits count towards their weight.
+///
+/// Can only be called during the registration phase, before any participants
+/// have registered.
+///
+/// `lockup_kinds`: Bitmask of the vsr::LockupKinds that count, with bit
+///     `1 << kind as u8` for each kind. Zero to count all kinds.
+/// `min_lockup_end_ts`: Only deposits that are guaranteed to stay locked until
+///     at least this time count. Constant lockups count with the end of a
+///     cliff lockup of the same duration. Zero for no minimum.
+#[derive(Accounts)]
+pub struct SetDepositFilter<'info> {
+    #[account(mut, has_one = admin)]
+    pub distribution: AccountLoader<'info, Distribution>,
+    pub admin: Signer<'info>,
+}
+
+pub fn set_deposit_filter(
+    ctx: Context<SetDepositFilter>,
+    lockup_kinds: u8,
+    min_lockup_end_ts: u64,
+) -> Result<()> {
+    let mut distribution = ctx.accounts.distribution.load_mut()?;
+    require!(
+        !distribution.is_cancelled(),
+        ErrorKind::DistributionCancelled
+    );
+    require!(
+        distribution.in_registration_phase(),
+        ErrorKind::ClaimPhaseAlreadyStarted
+    );
+    require!(
+        distribution.participant_count == 0,
+        ErrorKind::ParticipantsAlreadyRegistered
+    );
+    require!(
+        lockup_kinds < 1 << (vsr::LockupKind::Constant as u8 + 1),
+        ErrorKind::InvalidDepositFilter
+    );
+
+    distribution.deposit_lockup_kinds = lockup_kinds;
+    distribution.min_lockup_end_ts = min_lockup_end_ts;
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Turns the distribution into a rolling distribution with several epochs.
+///
+/// The epochs start at registration_end_ts and follow each other without
+/// gaps until the claim phase ends. Each epoch distributes the tokens that are
+/// in the vault at its first claim, by the weights that were registered
+/// before it started. Participants can claim once per epoch, and anything
+/// that isn't claimed carries over to the next epoch.
+///
+/// Participants stay registered across epochs. They can register, update
+/// their weight or withdraw at any time, which takes effect in the next epoch.
+///
+/// Can only be called during the registration phase. Vesting, reward vaults
+/// and verification windows aren't supported.
+///
+/// `epoch_duration`: The length of each epoch in seconds. Zero to turn
+///     epochs off again.
+#[derive(Accounts)]
+pub struct SetEpochs<'info> {
+    #[account(mut, has_one = admin)]
+    pub distribution: AccountLoader<'info, Distribution>,
+    pub admin: Signer<'info>,
+}
+
+pub fn set_epochs(ctx: Context<SetEpochs>, epoch_duration: u64) -> Result<()> {
+    let mut distribution = ctx.accounts.distribution.load_mut()?;
+    require!(
+        !distribution.is_cancelled(),
+        ErrorKind::DistributionCancelled
+    );
+    require!(
+        distribution.in_registration_phase(),
+        ErrorKind::ClaimPhaseAlreadyStarted
+    );
+    require!(
+        epoch_duration == 0
+            || (distribution.vesting_end_ts == 0
+                && distribution.reward_vault_count == 0
+                && distribution.verification_duration == 0),
+        ErrorKind::NotSupportedByEpochDistributions
+    );
+    require!(
+        epoch_duration == 0 || !distribution.is_merkle_distribution(),
+        ErrorKind::NotSupportedByMerkleDistributions
+    );
+
+    distribution.epoch_duration = epoch_duration;
+
+    Ok(())
+}
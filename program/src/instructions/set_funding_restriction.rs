@@ -0,0 +1,40 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Restricts FundDistribution to the registration phase, so participants
+/// know the full amount before the claim phase starts.
+///
+/// Can only be called by the admin or funder during the registration phase.
+/// Direct token transfers to the vault can't be prevented.
+///
+/// `registration_only`: If set, FundDistribution fails after registration.
+#[derive(Accounts)]
+pub struct SetFundingRestriction<'info> {
+    #[account(mut)]
+    pub distribution: AccountLoader<'info, Distribution>,
+    pub authority: Signer<'info>,
+}
+
+pub fn set_funding_restriction(
+    ctx: Context<SetFundingRestriction>,
+    registration_only: bool,
+) -> Result<()> {
+    let mut distribution = ctx.accounts.distribution.load_mut()?;
+    require!(
+        distribution.is_funder(ctx.accounts.authority.key),
+        ErrorKind::Unauthorized
+    );
+    require!(
+        !distribution.is_cancelled(),
+        ErrorKind::DistributionCancelled
+    );
+    require!(
+        distribution.in_registration_phase(),
+        ErrorKind::ClaimPhaseAlreadyStarted
+    );
+
+    distribution.funding_registration_only = registration_only as u8;
+
+    Ok(())
+}
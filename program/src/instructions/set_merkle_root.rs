@@ -0,0 +1,58 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Turns the distribution into a merkle distribution, where the amount each
+/// voter gets is computed off-chain.
+///
+/// Instead of registering, voters claim with ClaimWithProof, proving that
+/// `(index, voter, amount)` is a leaf of the merkle tree, see the merkle module.
+/// The vault must be funded with the sum of all amounts.
+///
+/// Can only be called during the registration phase, before any participants
+/// have registered. Epochs, vesting, reward vaults, verification windows and
+/// claim lockups aren't supported.
+///
+/// `merkle_root`: The root of the tree.
+/// `leaf_count`: The number of leaves in the tree. Zero to turn the merkle
+///     distribution off again.
+#[derive(Accounts)]
+pub struct SetMerkleRoot<'info> {
+    #[account(mut, has_one = admin)]
+    pub distribution: AccountLoader<'info, Distribution>,
+    pub admin: Signer<'info>,
+}
+
+pub fn set_merkle_root(
+    ctx: Context<SetMerkleRoot>,
+    merkle_root: [u8; 32],
+    leaf_count: u32,
+) -> Result<()> {
+    let mut distribution = ctx.accounts.distribution.load_mut()?;
+    require!(
+        !distribution.is_cancelled(),
+        ErrorKind::DistributionCancelled
+    );
+    require!(
+        distribution.in_registration_phase(),
+        ErrorKind::ClaimPhaseAlreadyStarted
+    );
+    require!(
+        distribution.participant_count == 0,
+        ErrorKind::ParticipantsAlreadyRegistered
+    );
+    require!(
+        leaf_count == 0
+            || (!distribution.is_epoch_distribution()
+                && distribution.vesting_end_ts == 0
+                && distribution.reward_vault_count == 0
+                && distribution.verification_duration == 0
+                && distribution.claim_lockup_kind().is_none()),
+        ErrorKind::NotSupportedByMerkleDistributions
+    );
+
+    distribution.merkle_root = merkle_root;
+    distribution.merkle_leaf_count = leaf_count;
+
+    Ok(())
+}
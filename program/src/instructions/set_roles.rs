@@ -0,0 +1,27 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Assigns roles that share some of the admin's permissions, so the admin
+/// can be a multisig while another key handles routine actions.
+///
+/// The admin keeps all permissions of the roles.
+///
+/// `funder`: May AddRewardVault and SetFundingRestriction. Once set, only the
+///     funder and the admin may FundDistribution. Zero for none, which lets
+///     anyone fund.
+/// `canceller`: May CancelDistribution, but only refunding to token accounts
+///     owned by the admin. Zero for none.
+#[derive(Accounts)]
+pub struct SetRoles<'info> {
+    #[account(mut, has_one = admin)]
+    pub distribution: AccountLoader<'info, Distribution>,
+    pub admin: Signer<'info>,
+}
+
+pub fn set_roles(ctx: Context<SetRoles>, funder: Pubkey, canceller: Pubkey) -> Result<()> {
+    let mut distribution = ctx.accounts.distribution.load_mut()?;
+    distribution.funder = funder;
+    distribution.canceller = canceller;
+
+    Ok(())
+}
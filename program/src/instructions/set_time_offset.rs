@@ -1,8 +1,10 @@
-use crate::error::*;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
 /// Debug-only instruction, used to advance time in tests
+///
+/// Only compiled with the localnet or test-bpf feature, mainnet builds don't
+/// contain it.
 #[derive(Accounts)]
 #[instruction(time_offset: i64)]
 pub struct SetTimeOffset<'info> {
@@ -12,7 +14,6 @@ pub struct SetTimeOffset<'info> {
 }
 
 pub fn set_time_offset(ctx: Context<SetTimeOffset>, time_offset: i64) -> Result<()> {
-    // TODO: Limit using this instruction to one specific admin key in tests
     let distribution = &mut ctx.accounts.distribution.load_mut()?;
     distribution.time_offset = time_offset;
     Ok(())
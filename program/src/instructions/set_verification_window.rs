@@ -0,0 +1,53 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Delays the claim phase by a verification window after registration_end_ts.
+///
+/// Weights are registered early, so a voter could lose locked tokens after
+/// registering, for example to a clawback, and still claim with their stored
+/// weight. During the window, anyone can ChallengeParticipant to recompute a
+/// participant's weight and reduce it. Claims open once the window ends.
+///
+/// Can only be called during the registration phase. Not supported by epoch
+/// and merkle distributions.
+///
+/// `verification_duration`: The length of the window in seconds. The window
+///     must end before claim_end_ts. Zero for no window.
+#[derive(Accounts)]
+pub struct SetVerificationWindow<'info> {
+    #[account(mut, has_one = admin)]
+    pub distribution: AccountLoader<'info, Distribution>,
+    pub admin: Signer<'info>,
+}
+
+pub fn set_verification_window(
+    ctx: Context<SetVerificationWindow>,
+    verification_duration: u64,
+) -> Result<()> {
+    let mut distribution = ctx.accounts.distribution.load_mut()?;
+    require!(
+        !distribution.is_cancelled(),
+        ErrorKind::DistributionCancelled
+    );
+    require!(
+        distribution.in_registration_phase(),
+        ErrorKind::ClaimPhaseAlreadyStarted
+    );
+    require!(
+        verification_duration == 0 || !distribution.is_epoch_distribution(),
+        ErrorKind::NotSupportedByEpochDistributions
+    );
+    require!(
+        verification_duration == 0 || !distribution.is_merkle_distribution(),
+        ErrorKind::NotSupportedByMerkleDistributions
+    );
+
+    distribution.verification_duration = verification_duration;
+    require!(
+        distribution.claim_end_ts == 0 || distribution.claim_end_ts > distribution.claim_start_ts(),
+        ErrorKind::ClaimEndDuringVerification
+    );
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Configures linear vesting of the participants' shares.
+///
+/// Can only be called during the registration phase.
+///
+/// `vesting_start_ts`: Shares start vesting linearly at this time.
+/// `vesting_cliff_ts`: Nothing can be claimed before this time. Zero for no
+///     cliff, otherwise it must lie within the vesting period.
+/// `vesting_end_ts`: Shares are fully vested at this time. Must be before
+///     claim_end_ts, if set. Zero to disable vesting.
+#[derive(Accounts)]
+pub struct SetVesting<'info> {
+    #[account(mut, has_one = admin)]
+    pub distribution: AccountLoader<'info, Distribution>,
+    pub admin: Signer<'info>,
+}
+
+pub fn set_vesting(
+    ctx: Context<SetVesting>,
+    vesting_start_ts: u64,
+    vesting_cliff_ts: u64,
+    vesting_end_ts: u64,
+) -> Result<()> {
+    let mut distribution = ctx.accounts.distribution.load_mut()?;
+    require!(
+        !distribution.is_cancelled(),
+        ErrorKind::DistributionCancelled
+    );
+    require!(
+        distribution.in_registration_phase(),
+        ErrorKind::ClaimPhaseAlreadyStarted
+    );
+    require!(
+        !distribution.is_epoch_distribution(),
+        ErrorKind::NotSupportedByEpochDistributions
+    );
+    require!(
+        !distribution.is_merkle_distribution(),
+        ErrorKind::NotSupportedByMerkleDistributions
+    );
+
+    if vesting_end_ts == 0 {
+        require!(
+            vesting_start_ts == 0 && vesting_cliff_ts == 0,
+            ErrorKind::InvalidVestingSchedule
+        );
+    } else {
+        require!(
+            vesting_start_ts < vesting_end_ts,
+            ErrorKind::InvalidVestingSchedule
+        );
+        require!(
+            vesting_cliff_ts == 0
+                || (vesting_start_ts <= vesting_cliff_ts && vesting_cliff_ts <= vesting_end_ts),
+            ErrorKind::InvalidVestingSchedule
+        );
+        require!(
+            distribution.claim_end_ts == 0 || vesting_end_ts <= distribution.claim_end_ts,
+            ErrorKind::InvalidVestingSchedule
+        );
+    }
+
+    distribution.vesting_start_ts = vesting_start_ts;
+    distribution.vesting_cliff_ts = vesting_cliff_ts;
+    distribution.vesting_end_ts = vesting_end_ts;
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use voter_stake_registry::state as vsr;
+
+/// Restricts which of the registrar's voting mints count towards a voter's
+/// weight.
+///
+/// Can only be called during the registration phase, before any participants
+/// have registered.
+///
+/// `voting_mints`: The indexes of the registrar's voting mints whose deposits
+///     count. Empty to count all voting mints.
+#[derive(Accounts)]
+pub struct SetVotingMints<'info> {
+    #[account(mut, has_one = admin, has_one = registrar)]
+    pub distribution: AccountLoader<'info, Distribution>,
+    pub admin: Signer<'info>,
+
+    pub registrar: AccountLoader<'info, vsr::Registrar>,
+}
+
+pub fn set_voting_mints(ctx: Context<SetVotingMints>, voting_mints: Vec<u8>) -> Result<()> {
+    let mut distribution = ctx.accounts.distribution.load_mut()?;
+    require!(
+        !distribution.is_cancelled(),
+        ErrorKind::DistributionCancelled
+    );
+    require!(
+        distribution.in_registration_phase(),
+        ErrorKind::ClaimPhaseAlreadyStarted
+    );
+    require!(
+        distribution.participant_count == 0,
+        ErrorKind::ParticipantsAlreadyRegistered
+    );
+
+    let registrar = ctx.accounts.registrar.load()?;
+    let mut voting_mint_mask = 0u8;
+    for index in voting_mints {
+        let mint_config = registrar.voting_mints.get(index as usize);
+        require!(
+            index < 8 && mint_config.map_or(false, |config| config.mint != Pubkey::default()),
+            ErrorKind::InvalidVotingMints
+        );
+        voting_mint_mask |= 1 << index;
+    }
+
+    distribution.voting_mints = voting_mint_mask;
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Caps the weight of each participant, to limit how much of the
+/// distribution a single large locker can get.
+///
+/// Can only be called during the registration phase, before any participants
+/// have registered.
+///
+/// `max_weight_per_participant`: The cap, zero to disable it.
+/// `in_bps`: If not set, the cap is an absolute weight that is applied when
+///     participants register, so the part above it isn't counted at all. It
+///     can't be below min_weight, or nobody could register.
+///     If set, the cap is in basis points of participant_total_weight and
+///     applied when claiming. The part of a share above it stays in the vault.
+#[derive(Accounts)]
+pub struct SetWeightCap<'info> {
+    #[account(mut, has_one = admin)]
+    pub distribution: AccountLoader<'info, Distribution>,
+    pub admin: Signer<'info>,
+}
+
+pub fn set_weight_cap(
+    ctx: Context<SetWeightCap>,
+    max_weight_per_participant: u64,
+    in_bps: bool,
+) -> Result<()> {
+    let mut distribution = ctx.accounts.distribution.load_mut()?;
+    require!(
+        !distribution.is_cancelled(),
+        ErrorKind::DistributionCancelled
+    );
+    require!(
+        distribution.in_registration_phase(),
+        ErrorKind::ClaimPhaseAlreadyStarted
+    );
+    require!(
+        distribution.participant_count == 0,
+        ErrorKind::ParticipantsAlreadyRegistered
+    );
+    require!(
+        !in_bps || max_weight_per_participant <= 10_000,
+        ErrorKind::InvalidWeightCap
+    );
+    require!(
+        in_bps
+            || max_weight_per_participant == 0
+            || max_weight_per_participant >= distribution.min_weight,
+        ErrorKind::WeightCapBelowMinWeight
+    );
+
+    distribution.max_weight_per_participant = max_weight_per_participant;
+    distribution.max_weight_in_bps = in_bps as u8;
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Evaluates locked vote weight at several times instead of only at weight_ts.
+///
+/// A participant's weight is the average of their locked vote weights at the
+/// checkpoints, weighted by the checkpoint factors. That rewards lockups that
+/// stay locked over the whole horizon over ones that just reach weight_ts.
+///
+/// Can only be called during the registration phase, before any participants
+/// have registered.
+///
+/// `weight_ts`: The times at which locked vote weight is evaluated. Each must be
+///     >= registration_end_ts. Empty to go back to only using weight_ts.
+/// `factors_bps`: The factor of each checkpoint in basis points. Must have the
+///     same length as weight_ts and add up to 10000.
+#[derive(Accounts)]
+pub struct SetWeightCheckpoints<'info> {
+    #[account(mut, has_one = admin)]
+    pub distribution: AccountLoader<'info, Distribution>,
+    pub admin: Signer<'info>,
+}
+
+pub fn set_weight_checkpoints(
+    ctx: Context<SetWeightCheckpoints>,
+    weight_ts: Vec<u64>,
+    factors_bps: Vec<u16>,
+) -> Result<()> {
+    let mut distribution = ctx.accounts.distribution.load_mut()?;
+    require!(
+        !distribution.is_cancelled(),
+        ErrorKind::DistributionCancelled
+    );
+    require!(
+        distribution.in_registration_phase(),
+        ErrorKind::ClaimPhaseAlreadyStarted
+    );
+    require!(
+        distribution.participant_count == 0,
+        ErrorKind::ParticipantsAlreadyRegistered
+    );
+    require!(
+        weight_ts.len() == factors_bps.len() && weight_ts.len() <= MAX_WEIGHT_CHECKPOINTS,
+        ErrorKind::InvalidWeightCheckpoints
+    );
+    require!(
+        weight_ts.is_empty() || factors_bps.iter().map(|f| *f as u64).sum::<u64>() == 10_000,
+        ErrorKind::InvalidWeightCheckpoints
+    );
+    let registration_end_ts = distribution.registration_end_ts;
+    require!(
+        weight_ts.iter().all(|ts| *ts >= registration_end_ts),
+        ErrorKind::WeightNotDuringRegistration
+    );
+
+    for (i, (ts, factor_bps)) in weight_ts.iter().zip(&factors_bps).enumerate() {
+        distribution.weight_checkpoints[i] = WeightCheckpoint {
+            ts: *ts,
+            factor_bps: *factor_bps as u64,
+        };
+    }
+    distribution.weight_checkpoint_count = weight_ts.len() as u8;
+
+    Ok(())
+}
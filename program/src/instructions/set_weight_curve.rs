@@ -0,0 +1,41 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Sets how locked vote weight is turned into participant weight.
+///
+/// Can only be called during the registration phase, before any participants
+/// have registered.
+///
+/// `weight_curve`: The WeightCurve as u8: 0 for linear, 1 for square root,
+///     2 for log2(1 + weight).
+#[derive(Accounts)]
+pub struct SetWeightCurve<'info> {
+    #[account(mut, has_one = admin)]
+    pub distribution: AccountLoader<'info, Distribution>,
+    pub admin: Signer<'info>,
+}
+
+pub fn set_weight_curve(ctx: Context<SetWeightCurve>, weight_curve: u8) -> Result<()> {
+    let mut distribution = ctx.accounts.distribution.load_mut()?;
+    require!(
+        !distribution.is_cancelled(),
+        ErrorKind::DistributionCancelled
+    );
+    require!(
+        distribution.in_registration_phase(),
+        ErrorKind::ClaimPhaseAlreadyStarted
+    );
+    require!(
+        distribution.participant_count == 0,
+        ErrorKind::ParticipantsAlreadyRegistered
+    );
+    require!(
+        WeightCurve::from_u8(weight_curve).is_some(),
+        ErrorKind::InvalidWeightCurve
+    );
+
+    distribution.weight_curve = weight_curve;
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Postpones the end of the registration phase and/or changes weight_ts,
+/// for example when a governance vote delays the campaign.
+///
+/// Can only be called during the registration phase, and registration_end_ts
+/// can only be postponed, never brought forward. Registered participants stay
+/// registered:
+/// - when weight_ts moves earlier, they keep their weights. Weights never
+///   decrease with an earlier weight_ts, so the stored weights stay lower
+///   bounds of the new ones and participants can UpdateParticipant to raise
+///   them.
+/// - when weight_ts moves later, their weights may be too high and become
+///   stale, counting as zero until UpdateParticipant or RefreshParticipant
+///   re-evaluates them before registration ends. Anyone can
+///   RefreshParticipant, see Distribution::participant_weight_is_stale.
+/// - with weight checkpoints, weights don't depend on weight_ts, so moving it
+///   doesn't affect them either way.
+///
+/// `registration_end_ts`: The new end of the registration phase. Must lie
+///     before weight_ts and all weight checkpoints, and the verification
+///     window must end before claim_end_ts. Zero to keep
+///     the current value.
+/// `weight_ts`: The new time at which the locked token vote weight is
+///     evaluated. Must be >= registration_end_ts. Zero to keep the current
+///     value.
+#[derive(Accounts)]
+pub struct UpdateDistributionConfig<'info> {
+    #[account(mut, has_one = admin)]
+    pub distribution: AccountLoader<'info, Distribution>,
+    pub admin: Signer<'info>,
+}
+
+pub fn update_distribution_config(
+    ctx: Context<UpdateDistributionConfig>,
+    registration_end_ts: u64,
+    weight_ts: u64,
+) -> Result<()> {
+    let mut distribution = ctx.accounts.distribution.load_mut()?;
+    require!(
+        !distribution.is_cancelled(),
+        ErrorKind::DistributionCancelled
+    );
+    require!(
+        distribution.in_registration_phase(),
+        ErrorKind::ClaimPhaseAlreadyStarted
+    );
+
+    let registration_end_ts = if registration_end_ts == 0 {
+        distribution.registration_end_ts
+    } else {
+        registration_end_ts
+    };
+    let weight_ts = if weight_ts == 0 {
+        distribution.weight_ts
+    } else {
+        weight_ts
+    };
+
+    require!(
+        registration_end_ts >= distribution.registration_end_ts,
+        ErrorKind::RegistrationEndMustNotMoveForward
+    );
+    require!(
+        registration_end_ts <= weight_ts
+            && distribution
+                .weight_checkpoints()
+                .iter()
+                .all(|checkpoint| checkpoint.ts >= registration_end_ts),
+        ErrorKind::WeightNotDuringRegistration
+    );
+    require!(
+        distribution.claim_end_ts == 0 || distribution.claim_end_ts > registration_end_ts,
+        ErrorKind::ClaimEndDuringRegistration
+    );
+    require!(
+        distribution.claim_end_ts == 0
+            || distribution.claim_end_ts
+                > registration_end_ts
+                    .checked_add(distribution.verification_duration)
+                    .unwrap(),
+        ErrorKind::ClaimEndDuringVerification
+    );
+
+    // weight checkpoints replace weight_ts, so registered weights don't depend on it
+    if weight_ts > distribution.weight_ts
+        && distribution.participant_count > 0
+        && distribution.weight_checkpoint_count == 0
+    {
+        distribution.weight_revision = distribution.weight_revision.checked_add(1).unwrap();
+        distribution.participant_total_weight = 0;
+    }
+    distribution.registration_end_ts = registration_end_ts;
+    distribution.weight_ts = weight_ts;
+
+    Ok(())
+}
@@ -7,6 +7,15 @@ use voter_stake_registry::state as vsr;
 ///
 /// When a voter locks up more tokens, their weight will increase. Call this to
 /// let the distribution and participant accounts know about the update.
+/// RefreshParticipant also handles decreases.
+///
+/// After UpdateDistributionConfig moved weight_ts later, the registered weight
+/// is stale and counts as zero, see Distribution::participant_weight_is_stale.
+/// This re-evaluates it, and the new weight may be lower than the stale one.
+///
+/// In epoch distributions, this refreshes the weight for the next epoch at any
+/// time before the claim phase ends. Since lockups run down, the weight may
+/// also decrease, down to zero.
 #[derive(Accounts)]
 pub struct UpdateParticipant<'info> {
     #[account(
@@ -32,7 +41,11 @@ pub struct UpdateParticipant<'info> {
 pub fn update_participant(ctx: Context<UpdateParticipant>) -> Result<()> {
     let mut distribution = ctx.accounts.distribution.load_mut()?;
     require!(
-        distribution.in_registration_phase(),
+        !distribution.is_cancelled(),
+        ErrorKind::DistributionCancelled
+    );
+    require!(
+        distribution.registration_open(),
         ErrorKind::TooLateToRegister
     );
 
@@ -40,24 +53,26 @@ pub fn update_participant(ctx: Context<UpdateParticipant>) -> Result<()> {
     let voter = ctx.accounts.voter.load()?;
     let registrar = ctx.accounts.registrar.load()?;
     let weight = distribution.voter_weight(&registrar, &voter)?;
-    require!(weight > 0, ErrorKind::NoLockedVoteWeight);
+    require!(
+        weight > 0 || distribution.is_epoch_distribution(),
+        ErrorKind::NoLockedVoteWeight
+    );
 
     // unregister old weight and set the new one
     let mut participant = ctx.accounts.participant.load_mut()?;
     // it should be impossible for locked token weight to decrease on a second call
-    // since only fully-locked tokens enter the computation
+    // since only fully-locked tokens enter the computation, unless the weight
+    // is for a later epoch or the old weight is stale
     require!(
-        weight >= participant.weight,
+        weight >= distribution.participant_weight(&participant)
+            || distribution.is_epoch_distribution(),
         ErrorKind::WeightMustNotDecrease
     );
-    distribution.participant_total_weight = distribution
-        .participant_total_weight
-        .saturating_sub(participant.weight.into());
-    participant.weight = weight;
-    distribution.participant_total_weight = distribution
-        .participant_total_weight
-        .checked_add(weight.into())
-        .unwrap();
+    require!(
+        weight >= distribution.min_weight || distribution.is_epoch_distribution(),
+        ErrorKind::WeightBelowMinimum
+    );
+    distribution.set_participant_weight(&mut participant, weight);
 
     Ok(())
 }
@@ -5,6 +5,7 @@ use instructions::*;
 mod error;
 pub mod events;
 mod instructions;
+pub mod merkle;
 pub mod state;
 
 #[macro_use]
@@ -22,8 +23,127 @@ pub mod distribute_by_locked_vote_weight {
         index: u64,
         end_ts: u64,
         weight_ts: u64,
+        claim_end_ts: u64,
+        keep_participants: bool,
+        min_weight: u64,
     ) -> Result<()> {
-        instructions::create_distribution(ctx, index, end_ts, weight_ts)
+        instructions::create_distribution(
+            ctx,
+            index,
+            end_ts,
+            weight_ts,
+            claim_end_ts,
+            keep_participants,
+            min_weight,
+        )
+    }
+
+    pub fn set_admin(ctx: Context<SetAdmin>, new_admin: Pubkey) -> Result<()> {
+        instructions::set_admin(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin(ctx)
+    }
+
+    pub fn set_roles(ctx: Context<SetRoles>, funder: Pubkey, canceller: Pubkey) -> Result<()> {
+        instructions::set_roles(ctx, funder, canceller)
+    }
+
+    pub fn update_distribution_config(
+        ctx: Context<UpdateDistributionConfig>,
+        registration_end_ts: u64,
+        weight_ts: u64,
+    ) -> Result<()> {
+        instructions::update_distribution_config(ctx, registration_end_ts, weight_ts)
+    }
+
+    pub fn set_vesting(
+        ctx: Context<SetVesting>,
+        vesting_start_ts: u64,
+        vesting_cliff_ts: u64,
+        vesting_end_ts: u64,
+    ) -> Result<()> {
+        instructions::set_vesting(ctx, vesting_start_ts, vesting_cliff_ts, vesting_end_ts)
+    }
+
+    pub fn set_claim_end(ctx: Context<SetClaimEnd>, claim_end_ts: u64) -> Result<()> {
+        instructions::set_claim_end(ctx, claim_end_ts)
+    }
+
+    pub fn set_claim_lockup(
+        ctx: Context<SetClaimLockup>,
+        lockup_kind: u8,
+        lockup_periods: u32,
+        lockup_required: bool,
+    ) -> Result<()> {
+        instructions::set_claim_lockup(ctx, lockup_kind, lockup_periods, lockup_required)
+    }
+
+    pub fn set_verification_window(
+        ctx: Context<SetVerificationWindow>,
+        verification_duration: u64,
+    ) -> Result<()> {
+        instructions::set_verification_window(ctx, verification_duration)
+    }
+
+    pub fn set_weight_cap(
+        ctx: Context<SetWeightCap>,
+        max_weight_per_participant: u64,
+        in_bps: bool,
+    ) -> Result<()> {
+        instructions::set_weight_cap(ctx, max_weight_per_participant, in_bps)
+    }
+
+    pub fn set_deposit_filter(
+        ctx: Context<SetDepositFilter>,
+        lockup_kinds: u8,
+        min_lockup_end_ts: u64,
+    ) -> Result<()> {
+        instructions::set_deposit_filter(ctx, lockup_kinds, min_lockup_end_ts)
+    }
+
+    pub fn set_voting_mints(ctx: Context<SetVotingMints>, voting_mints: Vec<u8>) -> Result<()> {
+        instructions::set_voting_mints(ctx, voting_mints)
+    }
+
+    pub fn set_epochs(ctx: Context<SetEpochs>, epoch_duration: u64) -> Result<()> {
+        instructions::set_epochs(ctx, epoch_duration)
+    }
+
+    pub fn set_merkle_root(
+        ctx: Context<SetMerkleRoot>,
+        merkle_root: [u8; 32],
+        leaf_count: u32,
+    ) -> Result<()> {
+        instructions::set_merkle_root(ctx, merkle_root, leaf_count)
+    }
+
+    pub fn set_weight_checkpoints(
+        ctx: Context<SetWeightCheckpoints>,
+        weight_ts: Vec<u64>,
+        factors_bps: Vec<u16>,
+    ) -> Result<()> {
+        instructions::set_weight_checkpoints(ctx, weight_ts, factors_bps)
+    }
+
+    pub fn set_weight_curve(ctx: Context<SetWeightCurve>, weight_curve: u8) -> Result<()> {
+        instructions::set_weight_curve(ctx, weight_curve)
+    }
+
+    pub fn set_funding_restriction(
+        ctx: Context<SetFundingRestriction>,
+        registration_only: bool,
+    ) -> Result<()> {
+        instructions::set_funding_restriction(ctx, registration_only)
+    }
+
+    pub fn fund_distribution(ctx: Context<FundDistribution>, amount: u64) -> Result<()> {
+        instructions::fund_distribution(ctx, amount)
+    }
+
+    pub fn add_reward_vault(ctx: Context<AddRewardVault>) -> Result<()> {
+        instructions::add_reward_vault(ctx)
     }
 
     pub fn create_participant(ctx: Context<CreateParticipant>) -> Result<()> {
@@ -34,15 +154,82 @@ pub mod distribute_by_locked_vote_weight {
         instructions::update_participant(ctx)
     }
 
-    pub fn claim(ctx: Context<Claim>) -> Result<()> {
+    pub fn refresh_participant(ctx: Context<RefreshParticipant>) -> Result<()> {
+        instructions::refresh_participant(ctx)
+    }
+
+    pub fn challenge_participant(ctx: Context<ChallengeParticipant>) -> Result<()> {
+        instructions::challenge_participant(ctx)
+    }
+
+    pub fn claim<'info>(ctx: Context<'_, '_, '_, 'info, Claim<'info>>) -> Result<()> {
         instructions::claim(ctx)
     }
 
+    pub fn claim_with_proof(
+        ctx: Context<ClaimWithProof>,
+        index: u32,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::claim_with_proof(ctx, index, amount, proof)
+    }
+
+    pub fn claim_for<'info>(ctx: Context<'_, '_, '_, 'info, ClaimFor<'info>>) -> Result<()> {
+        instructions::claim_for(ctx)
+    }
+
+    pub fn claim_and_lock<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimAndLock<'info>>,
+        deposit_entry_index: u8,
+    ) -> Result<()> {
+        instructions::claim_and_lock(ctx, deposit_entry_index)
+    }
+
+    pub fn cancel_distribution<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelDistribution<'info>>,
+    ) -> Result<()> {
+        instructions::cancel_distribution(ctx)
+    }
+
+    pub fn close_participant(ctx: Context<CloseParticipant>) -> Result<()> {
+        instructions::close_participant(ctx)
+    }
+
+    pub fn close_claimed_participant(ctx: Context<CloseClaimedParticipant>) -> Result<()> {
+        instructions::close_claimed_participant(ctx)
+    }
+
+    pub fn close_orphaned_participant(ctx: Context<CloseOrphanedParticipant>) -> Result<()> {
+        instructions::close_orphaned_participant(ctx)
+    }
+
+    #[cfg(any(feature = "localnet", feature = "test-bpf"))]
     pub fn set_time_offset(ctx: Context<SetTimeOffset>, time_offset: i64) -> Result<()> {
         instructions::set_time_offset(ctx, time_offset)
     }
 
-    pub fn log_info(ctx: Context<LogInfo>) -> Result<()> {
+    pub fn reclaim_remainder<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReclaimRemainder<'info>>,
+    ) -> Result<()> {
+        instructions::reclaim_remainder(ctx)
+    }
+
+    pub fn close_distribution<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseDistribution<'info>>,
+    ) -> Result<()> {
+        instructions::close_distribution(ctx)
+    }
+
+    pub fn log_info<'info>(ctx: Context<'_, '_, '_, 'info, LogInfo<'info>>) -> Result<()> {
         instructions::log_info(ctx)
     }
+
+    pub fn migrate_distribution(ctx: Context<MigrateDistribution>) -> Result<()> {
+        instructions::migrate_distribution(ctx)
+    }
+
+    pub fn migrate_participant(ctx: Context<MigrateParticipant>) -> Result<()> {
+        instructions::migrate_participant(ctx)
+    }
 }
@@ -0,0 +1,61 @@
+//! Merkle proofs for distributions with off-chain computed amounts, see
+//! SetMerkleRoot.
+//!
+//! Leaves commit to `(index, voter, amount)`. Pairs of nodes are sorted before
+//! hashing, so proofs don't need to say on which side each sibling is. Leaves
+//! and inner nodes are hashed with different prefixes so one can't pass for
+//! the other.
+
+use anchor_lang::prelude::*;
+use solana_program::keccak;
+
+pub fn leaf_hash(index: u32, voter: &Pubkey, amount: u64) -> [u8; 32] {
+    keccak::hashv(&[
+        &[0],
+        &index.to_le_bytes(),
+        voter.as_ref(),
+        &amount.to_le_bytes(),
+    ])
+    .0
+}
+
+pub fn node_hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    keccak::hashv(&[&[1], left, right]).0
+}
+
+/// Whether `proof` shows that `leaf` is part of the tree with `root`.
+pub fn verify(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    proof
+        .iter()
+        .fold(leaf, |hash, sibling| node_hash(&hash, sibling))
+        == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_small_tree() {
+        let voters: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = voters
+            .iter()
+            .enumerate()
+            .map(|(i, voter)| leaf_hash(i as u32, voter, 100 * i as u64))
+            .collect();
+        // the odd leaf is carried up a level
+        let node01 = node_hash(&leaves[0], &leaves[1]);
+        let root = node_hash(&node01, &leaves[2]);
+
+        assert!(verify(&[leaves[1], leaves[2]], &root, leaves[0]));
+        assert!(verify(&[leaves[0], leaves[2]], &root, leaves[1]));
+        assert!(verify(&[node01], &root, leaves[2]));
+
+        // wrong amount, index or voter
+        assert!(!verify(&[node01], &root, leaf_hash(2, &voters[2], 201)));
+        assert!(!verify(&[node01], &root, leaf_hash(1, &voters[2], 200)));
+        assert!(!verify(&[node01], &root, leaf_hash(2, &voters[1], 200)));
+        assert!(!verify(&[], &root, leaves[0]));
+    }
+}
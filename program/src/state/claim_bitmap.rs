@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+/// Number of claims tracked by each ClaimBitmap.
+pub const CLAIMS_PER_BITMAP: u32 = 8 * 1024;
+
+/// Record of which leaves of a merkle distribution were claimed, see
+/// ClaimWithProof.
+///
+/// Each bitmap covers CLAIMS_PER_BITMAP consecutive leaf indexes, starting at
+/// page * CLAIMS_PER_BITMAP. They are created by the first claim in their range.
+#[account(zero_copy)]
+pub struct ClaimBitmap {
+    pub distribution: Pubkey,
+    pub page: u32,
+    pub reserved: [u8; 28],
+    pub bits: [u8; 1024],
+}
+const_assert!(std::mem::size_of::<ClaimBitmap>() == 32 + 4 + 28 + 1024);
+const_assert!(std::mem::size_of::<ClaimBitmap>() % 8 == 0);
+
+impl ClaimBitmap {
+    /// The page of the bitmap that tracks the leaf at `index`.
+    pub fn page_for(index: u32) -> u32 {
+        index / CLAIMS_PER_BITMAP
+    }
+
+    pub fn is_claimed(&self, index: u32) -> bool {
+        let (byte, mask) = Self::position(index);
+        self.bits[byte] & mask != 0
+    }
+
+    pub fn set_claimed(&mut self, index: u32) {
+        let (byte, mask) = Self::position(index);
+        self.bits[byte] |= mask;
+    }
+
+    fn position(index: u32) -> (usize, u8) {
+        let offset = index % CLAIMS_PER_BITMAP;
+        ((offset / 8) as usize, 1 << (offset % 8))
+    }
+}
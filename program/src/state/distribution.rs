@@ -1,32 +1,57 @@
 use crate::error::*;
+use crate::state::{
+    Participant, ParticipantRewards, RewardVault, Rewards, WeightCheckpoint, WeightCurve,
+};
 use anchor_lang::prelude::*;
 use voter_stake_registry::state as vsr;
 
+/// Maximum number of additional reward vaults per distribution.
+pub const MAX_REWARD_VAULTS: usize = 4;
+
+/// Maximum number of weight checkpoints per distribution.
+pub const MAX_WEIGHT_CHECKPOINTS: usize = 4;
+
+/// The size of Distribution accounts created by the first program version,
+/// without the discriminator.
+pub const LEGACY_DISTRIBUTION_SIZE: usize = 4 * 32 + 7 * 8 + 2 * 4 + 1 + 39;
+
 /// Instance of a voting rights distributor.
+///
+/// The first LEGACY_DISTRIBUTION_SIZE bytes keep the layout of the first
+/// program version, with new fields in place of its reserved bytes. The rest
+/// was appended, see MigrateDistribution.
 #[account(zero_copy)]
 pub struct Distribution {
+    /// can be replaced with SetAdmin and AcceptAdmin
     pub admin: Pubkey,
+
     pub registrar: Pubkey,
     pub mint: Pubkey,
     pub vault: Pubkey,
+
     pub index: u64,
 
     /// participants can only be created before this time
-    /// Claim can only be called after this time
+    /// Claim can only be called after this time and the verification window
+    /// can be postponed with UpdateDistributionConfig
     pub registration_end_ts: u64,
 
     /// the time for which the vote weight from locked tokens is computed
     /// if this is one year in the future, then only lockups that last for
     /// at least one year can contribute
+    /// ignored if there are weight checkpoints
+    /// can be changed with UpdateDistributionConfig
     pub weight_ts: u64,
 
     /// sum of the weights from all participants
     pub participant_total_weight: u128,
 
-    /// the amount of tokens seen in the distribution vault when the claim phase started
-    pub total_amount_to_distribute: u64,
+    /// first program version only: the amount of tokens seen in the vault when
+    /// the claim phase started, turned into rewards by MigrateDistribution
+    pub legacy_total_amount_to_distribute: u64,
 
     /// Debug only: time offset, to allow tests to move forward in time.
+    /// Ignored unless built with the localnet or test-bpf feature.
     pub time_offset: i64,
 
     pub participant_count: u32,
@@ -34,45 +59,644 @@ pub struct Distribution {
 
     pub bump: u8,
 
-    pub reserved: [u8; 39],
+    /// claims can only be made before this time, afterwards the admin may
+    /// reclaim whatever is left in the vault
+    /// zero means there is no claim deadline
+    /// can be extended with SetClaimEnd
+    pub claim_end_ts: u64,
+
+    /// set by CancelDistribution, nonzero if the distribution was cancelled
+    pub cancelled: u8,
+
+    /// if nonzero, Claim keeps the participant account around as a record
+    /// of the payout instead of closing it
+    pub keep_participants: u8,
+
+    /// optional linear vesting of claimed tokens, see SetVesting
+    /// participants' shares vest linearly from vesting_start_ts to
+    /// vesting_end_ts, but nothing can be claimed before vesting_cliff_ts
+    /// zero vesting_end_ts means everything is claimable immediately
+    pub vesting_start_ts: u64,
+    pub vesting_cliff_ts: u64,
+    pub vesting_end_ts: u64,
+
+    /// lockup periods for deposits made by ClaimAndLock, see SetClaimLockup
+    pub claim_lockup_periods: u32,
+
+    /// the vsr::LockupKind of deposits made by ClaimAndLock as u8
+    /// zero (LockupKind::None) means ClaimAndLock is disabled
+    pub claim_lockup_kind: u8,
+
+    /// if nonzero, participants must use ClaimAndLock
+    pub claim_lockup_required: u8,
+
+    /// additional reward tokens paid out alongside mint, see AddRewardVault
+    /// only the first reward_vault_count entries are used
+    pub reward_vaults: [RewardVault; MAX_REWARD_VAULTS],
+    pub reward_vault_count: u8,
+
+    /// accounting of the tokens in vault, updated by every claim
+    pub rewards: Rewards,
+
+    /// the amount of tokens deposited with FundDistribution
+    /// direct token transfers to the vault are not included
+    pub funded_amount: u64,
+
+    /// the number of FundDistribution calls, counting repeated calls by the
+    /// same funder separately; the Funded events record each funder
+    pub funding_call_count: u32,
+
+    /// if nonzero, FundDistribution can only be used during the registration
+    /// phase, see SetFundingRestriction
+    pub funding_registration_only: u8,
+
+    /// optional cap on the weight of each participant, see SetWeightCap
+    /// zero means no cap
+    pub max_weight_per_participant: u64,
+
+    /// if nonzero, max_weight_per_participant is in basis points of
+    /// participant_total_weight and applied when claiming
+    pub max_weight_in_bps: u8,
+
+    /// the WeightCurve applied to locked vote weight as u8, see SetWeightCurve
+    /// zero (WeightCurve::Linear) means weight is proportional to it
+    pub weight_curve: u8,
+
+    /// participants must register with at least this weight
+    pub min_weight: u64,
+
+    /// only deposits that are guaranteed to stay locked until at least this
+    /// time count towards a voter's weight, see SetDepositFilter
+    /// zero means no minimum
+    pub min_lockup_end_ts: u64,
+
+    /// bitmask of the vsr::LockupKinds (1 << kind as u8) of deposits that
+    /// count towards a voter's weight, see SetDepositFilter
+    /// zero means all deposits count
+    pub deposit_lockup_kinds: u8,
+
+    /// bitmask of the indexes of the registrar's voting mints whose deposits
+    /// count towards a voter's weight
+    /// zero means all voting mints count
+    pub voting_mints: u8,
+
+    /// optional times at which the vote weight from locked tokens is computed,
+    /// replacing weight_ts, see SetWeightCheckpoints
+    /// only the first weight_checkpoint_count entries are used
+    pub weight_checkpoints: [WeightCheckpoint; MAX_WEIGHT_CHECKPOINTS],
+    pub weight_checkpoint_count: u8,
+
+    /// epoch distributions only: the participant_total_weight that applies to
+    /// epochs before weight_change_epoch
+    pub prior_participant_total_weight: u128,
+
+    /// if nonzero, this is an epoch distribution with epochs of this many
+    /// seconds, starting at registration_end_ts, see SetEpochs
+    pub epoch_duration: u64,
+
+    /// epoch distributions only: the amount of tokens distributed in the epoch
+    /// before next_unstarted_epoch, which is the vault balance at its first claim
+    pub epoch_amount: u64,
+
+    /// epoch distributions only: the epoch from which participant_total_weight
+    /// applies, see set_participant_weight
+    pub weight_change_epoch: u32,
+
+    /// epoch distributions only: epochs before this one have had claims
+    pub next_unstarted_epoch: u32,
+
+    /// the root of the merkle tree of off-chain computed amounts, see SetMerkleRoot
+    pub merkle_root: [u8; 32],
+
+    /// if nonzero, this is a merkle distribution with this many leaves
+    pub merkle_leaf_count: u32,
+
+    /// the admin at creation, part of the distribution's address seeds
+    pub creator: Pubkey,
+
+    /// proposed by SetAdmin, becomes the admin once it calls AcceptAdmin
+    /// zero if no transfer is pending
+    pub pending_admin: Pubkey,
+
+    /// optional roles that share some of the admin's permissions, see SetRoles
+    /// zero if unassigned
+    pub funder: Pubkey,
+    pub canceller: Pubkey,
+
+    /// length of the window after registration_end_ts during which
+    /// participants can be challenged, claims open once it ends, see
+    /// SetVerificationWindow
+    pub verification_duration: u64,
+
+    /// bumped when UpdateDistributionConfig moves weight_ts later while
+    /// participants are registered, see participant_weight_is_stale
+    pub weight_revision: u32,
+
+    /// the weight of participants that were closed after their last claim,
+    /// later deposits are split among the others
+    pub closed_participant_weight: u128,
+
+    pub reserved: [u8; 12],
 }
-const_assert!(std::mem::size_of::<Distribution>() == 4 * 32 + 7 * 8 + 2 * 4 + 1 + 39);
+const_assert!(
+    std::mem::size_of::<Distribution>()
+        == LEGACY_DISTRIBUTION_SIZE
+            + 1
+            + MAX_REWARD_VAULTS * (2 * 32 + 16 + 2 * 8)
+            + 1
+            + (16 + 2 * 8)
+            + 8
+            + 4
+            + 1
+            + 8
+            + 2
+            + 2 * 8
+            + 2
+            + MAX_WEIGHT_CHECKPOINTS * 2 * 8
+            + 1
+            + 16
+            + 2 * 8
+            + 2 * 4
+            + 32
+            + 4
+            + 4 * 32
+            + 8
+            + 4
+            + 16
+            + 12
+);
 const_assert!(std::mem::size_of::<Distribution>() % 8 == 0);
 
 impl Distribution {
     pub fn clock_unix_timestamp(&self) -> u64 {
-        Clock::get()
-            .unwrap()
-            .unix_timestamp
-            .checked_add(self.time_offset)
-            .unwrap() as u64
+        offset_unix_timestamp(Clock::get().unwrap().unix_timestamp, self.time_offset)
     }
 
+    /// The weight a voter can register with, with the absolute weight cap applied.
     pub fn voter_weight(&self, registrar: &vsr::Registrar, voter: &vsr::Voter) -> Result<u64> {
+        let weight = self.uncapped_voter_weight(registrar, voter)?;
+        Ok(match self.absolute_weight_cap() {
+            Some(cap) => weight.min(cap),
+            None => weight,
+        })
+    }
+
+    /// The voter's guaranteed locked vote weight from eligible deposits,
+    /// transformed by the weight curve.
+    pub fn uncapped_voter_weight(
+        &self,
+        registrar: &vsr::Registrar,
+        voter: &vsr::Voter,
+    ) -> Result<u64> {
+        let locked_weight = self
+            .locked_weight_by_voting_mint(registrar, voter)?
+            .iter()
+            .fold(0u64, |sum, weight| sum.checked_add(*weight).unwrap());
+        Ok(self.weight_curve().apply(locked_weight))
+    }
+
+    /// The voter's guaranteed locked vote weight from eligible deposits, for
+    /// each of the registrar's voting mints.
+    ///
+    /// Deposits are eligible if they are in an allowed voting mint and pass the
+    /// deposit filter. The weight of a deposit is the average of its weights at
+    /// the weight checkpoints, weighted by their factors.
+    ///
+    /// In epoch distributions, weight is for the next epoch, so the checkpoints
+    /// are moved forward by the time from registration_end_ts to its start.
+    ///
+    /// If all deposits are eligible and there are no weight checkpoints, the
+    /// sum is the same as vsr's weight_locked_guaranteed.
+    pub fn locked_weight_by_voting_mint(
+        &self,
+        registrar: &vsr::Registrar,
+        voter: &vsr::Voter,
+    ) -> Result<Vec<u64>> {
         let now_ts = self.clock_unix_timestamp() as i64;
-        Ok(voter
-            .weight_locked_guaranteed(&registrar, now_ts, self.weight_ts as i64)
-            .map_err(|err| {
-                msg!("vsr error: {}", err);
-                ErrorKind::VoterStakeRegistryError
-            })?)
+        let checkpoints = self.weight_checkpoints();
+        let weight_ts_shift = self.weight_change_effective_epoch() as u64 * self.epoch_duration;
+        let mut weights = vec![0u64; registrar.voting_mints.len()];
+        for deposit in voter.deposits.iter().filter(|deposit| {
+            deposit.is_used
+                && self.voting_mint_allowed(deposit.voting_mint_config_idx as usize)
+                && self.deposit_eligible(deposit, now_ts)
+        }) {
+            let mint_index = deposit.voting_mint_config_idx as usize;
+            let mint_config = &registrar.voting_mints[mint_index];
+            let max_locked_vote_weight = mint_config
+                .max_extra_lockup_vote_weight(deposit.amount_initially_locked_native)
+                .map_err(vsr_error)?;
+            let mut weighted_sum = 0u128;
+            for checkpoint in checkpoints.iter() {
+                let weight = deposit
+                    .voting_power_locked_guaranteed(
+                        now_ts,
+                        (checkpoint.ts + weight_ts_shift) as i64,
+                        max_locked_vote_weight,
+                        mint_config.lockup_saturation_secs,
+                    )
+                    .map_err(vsr_error)?;
+                weighted_sum = weighted_sum
+                    .checked_add((weight as u128) * (checkpoint.factor_bps as u128))
+                    .unwrap();
+            }
+            let weight = u64::try_from(weighted_sum / 10_000).unwrap();
+            weights[mint_index] = weights[mint_index].checked_add(weight).unwrap();
+        }
+        Ok(weights)
+    }
+
+    /// The times at which locked vote weight is evaluated. Without weight
+    /// checkpoints, that's just weight_ts.
+    pub fn weight_checkpoints(&self) -> Vec<WeightCheckpoint> {
+        if self.weight_checkpoint_count == 0 {
+            return vec![WeightCheckpoint {
+                ts: self.weight_ts,
+                factor_bps: 10_000,
+            }];
+        }
+        self.weight_checkpoints[..self.weight_checkpoint_count as usize].to_vec()
+    }
+
+    /// Whether `key` may do what the funder role allows.
+    pub fn is_funder(&self, key: &Pubkey) -> bool {
+        *key == self.admin || (self.funder != Pubkey::default() && *key == self.funder)
+    }
+
+    /// Whether `key` may do what the canceller role allows.
+    pub fn is_canceller(&self, key: &Pubkey) -> bool {
+        *key == self.admin || (self.canceller != Pubkey::default() && *key == self.canceller)
+    }
+
+    /// Whether deposits in the registrar's voting mint at `mint_index` count.
+    pub fn voting_mint_allowed(&self, mint_index: usize) -> bool {
+        self.voting_mints == 0 || self.voting_mints & (1 << mint_index) != 0
+    }
+
+    /// Whether the deposit passes the deposit filter.
+    fn deposit_eligible(&self, deposit: &vsr::DepositEntry, now_ts: i64) -> bool {
+        let lockup = &deposit.lockup;
+        if self.deposit_lockup_kinds != 0
+            && self.deposit_lockup_kinds & (1 << lockup.kind as u8) == 0
+        {
+            return false;
+        }
+        // constant lockups never run down, but could be turned into a cliff
+        // lockup of the same duration at any time
+        let guaranteed_end_ts = now_ts as u64 + lockup.seconds_left(now_ts);
+        guaranteed_end_ts >= self.min_lockup_end_ts
+    }
+
+    pub fn weight_curve(&self) -> WeightCurve {
+        WeightCurve::from_u8(self.weight_curve).unwrap()
+    }
+
+    /// Registers a claim by the participant and returns the amounts owed to them.
+    ///
+    /// `vault_amounts` are the balances of the vault followed by those of the
+    /// reward vaults, the returned amounts are in the same order.
+    ///
+    /// Tokens that arrived in the vaults since the last claim are first split
+    /// among all participants by weight. With a basis points weight cap, the
+    /// part of a participant's share above the cap stays in the vault. The
+    /// participant then gets whatever of their earnings has vested and wasn't
+    /// claimed yet, so this can be called repeatedly as vesting progresses or
+    /// more funds arrive.
+    ///
+    /// Unless the distribution keeps participants, the participant is closed
+    /// after its last claim and its weight is added to
+    /// closed_participant_weight, so later deposits go to the others. The
+    /// basis points weight cap still applies to the total weight.
+    pub fn claim(
+        &mut self,
+        participant: &mut Participant,
+        vault_amounts: &[u64],
+    ) -> Result<Vec<u64>> {
+        require!(!self.is_cancelled(), ErrorKind::DistributionCancelled);
+        require!(!self.claim_phase_ended(), ErrorKind::ClaimPhaseEnded);
+        require!(self.in_claim_phase(), ErrorKind::NotInClaimPhase);
+        let reward_vault_count = self.reward_vault_count as usize;
+        assert_eq!(vault_amounts.len(), 1 + reward_vault_count);
+        if self.is_epoch_distribution() {
+            return Ok(vec![self.claim_epoch(participant, vault_amounts[0])?]);
+        }
+
+        let total_weight = self
+            .participant_total_weight
+            .checked_sub(self.closed_participant_weight)
+            .unwrap();
+        self.rewards.update(vault_amounts[0], total_weight);
+        for (reward_vault, &vault_amount) in self.reward_vaults[..reward_vault_count]
+            .iter_mut()
+            .zip(&vault_amounts[1..])
+        {
+            reward_vault.rewards.update(vault_amount, total_weight);
+        }
+
+        let now_ts = self.clock_unix_timestamp();
+        let mut amounts = Vec::with_capacity(vault_amounts.len());
+
+        let weight = self.claim_weight(self.participant_weight(participant));
+        self.rewards.settle(&mut participant.rewards, weight);
+        let amount = self.vested_unclaimed_amount(&mut participant.rewards, now_ts);
+        self.rewards.record_claim(amount);
+        amounts.push(amount);
+
+        for i in 0..reward_vault_count {
+            let participant_rewards = &mut participant.reward_vault_rewards[i];
+            self.reward_vaults[i]
+                .rewards
+                .settle(participant_rewards, weight);
+            let amount = self.vested_unclaimed_amount(participant_rewards, now_ts);
+            self.reward_vaults[i].rewards.record_claim(amount);
+            amounts.push(amount);
+        }
+
+        participant.claimed_ts = now_ts;
+        if !participant.is_claimed() && self.fully_vested(now_ts) {
+            participant.claimed = 1;
+            self.claim_count = self.claim_count.checked_add(1).unwrap();
+            if !self.keeps_participants() {
+                self.closed_participant_weight = self
+                    .closed_participant_weight
+                    .checked_add(self.participant_weight(participant).into())
+                    .unwrap();
+            }
+        }
+
+        Ok(amounts)
+    }
+
+    /// Registers a claim for the current epoch of an epoch distribution and
+    /// returns the amount owed to the participant.
+    ///
+    /// Each epoch distributes the tokens that are in the vault at its first
+    /// claim, by the weights that were registered before it started. Whatever
+    /// isn't claimed during an epoch carries over to the next one.
+    fn claim_epoch(&mut self, participant: &mut Participant, vault_amount: u64) -> Result<u64> {
+        let epoch = self.current_epoch().unwrap();
+        require!(
+            participant.next_claim_epoch <= epoch,
+            ErrorKind::AlreadyClaimed
+        );
+        if self.next_unstarted_epoch <= epoch {
+            self.epoch_amount = vault_amount;
+            self.next_unstarted_epoch = epoch.checked_add(1).unwrap();
+        }
+
+        let total_weight = self.epoch_participant_total_weight(epoch);
+        let epoch_weight = if self.participant_weight_is_stale(participant) {
+            0
+        } else {
+            participant.epoch_weight(epoch)
+        };
+        let weight = self.bps_capped_weight(epoch_weight, total_weight);
+        let amount = if total_weight == 0 {
+            0
+        } else {
+            u64::try_from(
+                (self.epoch_amount as u128)
+                    .checked_mul(weight as u128)
+                    .unwrap()
+                    / total_weight,
+            )
+            .unwrap()
+        };
+        self.rewards.record_claim(amount);
+
+        participant.next_claim_epoch = epoch.checked_add(1).unwrap();
+        participant.claimed_ts = self.clock_unix_timestamp();
+        Ok(amount)
+    }
+
+    /// Sets the participant's weight and updates participant_total_weight.
+    ///
+    /// In epoch distributions, the change only takes effect in the next epoch,
+    /// the weights for the current one are kept as prior weights.
+    pub fn set_participant_weight(&mut self, participant: &mut Participant, weight: u64) {
+        if self.participant_weight_is_stale(participant) {
+            // stale weights were already removed from participant_total_weight
+            participant.weight = 0;
+            participant.prior_weight = 0;
+            participant.weight_revision = self.weight_revision;
+        }
+        let epoch = self.weight_change_effective_epoch();
+        if epoch > self.weight_change_epoch {
+            self.prior_participant_total_weight = self.participant_total_weight;
+            self.weight_change_epoch = epoch;
+        }
+        if epoch > participant.weight_change_epoch {
+            participant.prior_weight = participant.weight;
+            participant.weight_change_epoch = epoch;
+        }
+        self.participant_total_weight = self
+            .participant_total_weight
+            .checked_sub(participant.weight.into())
+            .unwrap()
+            .checked_add(weight.into())
+            .unwrap();
+        participant.weight = weight;
+    }
+
+    /// Whether the participant's weight was evaluated before weight_ts last
+    /// moved later. Weights can be lower at a later weight_ts, so stale weights
+    /// count as zero until UpdateParticipant or RefreshParticipant re-evaluates
+    /// them.
+    pub fn participant_weight_is_stale(&self, participant: &Participant) -> bool {
+        participant.weight_revision != self.weight_revision
+    }
+
+    /// The participant's weight, zero if it is stale.
+    pub fn participant_weight(&self, participant: &Participant) -> u64 {
+        if self.participant_weight_is_stale(participant) {
+            0
+        } else {
+            participant.weight
+        }
+    }
+
+    pub fn is_merkle_distribution(&self) -> bool {
+        self.merkle_leaf_count != 0
+    }
+
+    pub fn is_epoch_distribution(&self) -> bool {
+        self.epoch_duration != 0
+    }
+
+    /// The current epoch of an epoch distribution, None before the first
+    /// epoch started.
+    pub fn current_epoch(&self) -> Option<u32> {
+        if !self.is_epoch_distribution() {
+            return None;
+        }
+        let now_ts = self.clock_unix_timestamp();
+        if now_ts < self.registration_end_ts {
+            return None;
+        }
+        Some(u32::try_from((now_ts - self.registration_end_ts) / self.epoch_duration).unwrap())
+    }
+
+    /// The first epoch in which weight changes made now apply.
+    fn weight_change_effective_epoch(&self) -> u32 {
+        self.current_epoch()
+            .map_or(0, |epoch| epoch.checked_add(1).unwrap())
+    }
+
+    /// The sum of the participant weights that apply in `epoch`.
+    pub fn epoch_participant_total_weight(&self, epoch: u32) -> u128 {
+        if epoch >= self.weight_change_epoch {
+            self.participant_total_weight
+        } else {
+            self.prior_participant_total_weight
+        }
+    }
+
+    /// Whether participants can register or update their weight. Epoch
+    /// distributions allow that until the claim phase ends.
+    pub fn registration_open(&self) -> bool {
+        self.in_registration_phase() || (self.is_epoch_distribution() && !self.claim_phase_ended())
+    }
+
+    /// Marks everything the participant earned and has vested as claimed and
+    /// returns the newly claimed amount.
+    fn vested_unclaimed_amount(&self, participant: &mut ParticipantRewards, now_ts: u64) -> u64 {
+        let vested = self.vested_amount(participant.earned_amount, now_ts);
+        let amount = vested.checked_sub(participant.claimed_amount).unwrap();
+        participant.claimed_amount = vested;
+        amount
+    }
+
+    pub fn fully_vested(&self, now_ts: u64) -> bool {
+        self.vesting_end_ts == 0 || now_ts >= self.vesting_end_ts
+    }
+
+    /// The part of `amount` that has vested at `now_ts`.
+    pub fn vested_amount(&self, amount: u64, now_ts: u64) -> u64 {
+        if self.fully_vested(now_ts) {
+            return amount;
+        }
+        if now_ts < self.vesting_start_ts.max(self.vesting_cliff_ts) {
+            return 0;
+        }
+        u64::try_from(
+            (amount as u128)
+                .checked_mul((now_ts - self.vesting_start_ts) as u128)
+                .unwrap()
+                .checked_div((self.vesting_end_ts - self.vesting_start_ts) as u128)
+                .unwrap(),
+        )
+        .unwrap()
     }
 
     pub fn in_registration_phase(&self) -> bool {
         self.clock_unix_timestamp() < self.registration_end_ts
     }
 
+    /// The time at which claims open, after the verification window.
+    pub fn claim_start_ts(&self) -> u64 {
+        self.registration_end_ts
+            .checked_add(self.verification_duration)
+            .unwrap()
+    }
+
+    /// Whether participants can be challenged with ChallengeParticipant.
+    pub fn in_verification_phase(&self) -> bool {
+        let now_ts = self.clock_unix_timestamp();
+        now_ts >= self.registration_end_ts && now_ts < self.claim_start_ts()
+    }
+
     pub fn in_claim_phase(&self) -> bool {
-        !self.in_registration_phase()
+        self.clock_unix_timestamp() >= self.claim_start_ts() && !self.claim_phase_ended()
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled != 0
+    }
+
+    pub fn keeps_participants(&self) -> bool {
+        self.keep_participants != 0
+    }
+
+    /// The lockup kind for deposits made by ClaimAndLock, if enabled.
+    pub fn claim_lockup_kind(&self) -> Option<vsr::LockupKind> {
+        lockup_kind_from_u8(self.claim_lockup_kind).filter(|&kind| kind != vsr::LockupKind::None)
+    }
+
+    pub fn claim_lockup_required(&self) -> bool {
+        self.claim_lockup_required != 0
+    }
+
+    fn absolute_weight_cap(&self) -> Option<u64> {
+        if self.max_weight_per_participant == 0 || self.max_weight_in_bps != 0 {
+            return None;
+        }
+        Some(self.max_weight_per_participant)
+    }
+
+    /// The weight claims are based on, with the basis points weight cap applied.
+    pub fn claim_weight(&self, weight: u64) -> u64 {
+        self.bps_capped_weight(weight, self.participant_total_weight)
+    }
+
+    fn bps_capped_weight(&self, weight: u64, total_weight: u128) -> u64 {
+        if self.max_weight_per_participant == 0 || self.max_weight_in_bps == 0 {
+            return weight;
+        }
+        let cap = total_weight
+            .checked_mul(self.max_weight_per_participant as u128)
+            .unwrap()
+            / 10_000;
+        weight.min(u64::try_from(cap).unwrap_or(u64::MAX))
+    }
+
+    pub fn funding_registration_only(&self) -> bool {
+        self.funding_registration_only != 0
+    }
+
+    pub fn claim_phase_ended(&self) -> bool {
+        self.claim_end_ts != 0 && self.clock_unix_timestamp() >= self.claim_end_ts
+    }
+
+    /// The reward vaults that are in use.
+    pub fn used_reward_vaults(&self) -> &[RewardVault] {
+        &self.reward_vaults[..self.reward_vault_count as usize]
+    }
+}
+
+pub fn vsr_error(err: impl std::fmt::Display) -> ErrorKind {
+    msg!("vsr error: {}", err);
+    ErrorKind::VoterStakeRegistryError
+}
+
+pub fn lockup_kind_from_u8(kind: u8) -> Option<vsr::LockupKind> {
+    match kind {
+        0 => Some(vsr::LockupKind::None),
+        1 => Some(vsr::LockupKind::Daily),
+        2 => Some(vsr::LockupKind::Monthly),
+        3 => Some(vsr::LockupKind::Cliff),
+        4 => Some(vsr::LockupKind::Constant),
+        _ => None,
     }
 }
 
+/// Applies the debug-only time offset in test builds.
+#[cfg(any(feature = "localnet", feature = "test-bpf"))]
+fn offset_unix_timestamp(unix_timestamp: i64, time_offset: i64) -> u64 {
+    unix_timestamp.checked_add(time_offset).unwrap() as u64
+}
+
+/// Mainnet builds never move time.
+#[cfg(not(any(feature = "localnet", feature = "test-bpf")))]
+fn offset_unix_timestamp(unix_timestamp: i64, _time_offset: i64) -> u64 {
+    unix_timestamp as u64
+}
+
 #[macro_export]
 macro_rules! distribution_seeds {
     ( $distribution:expr ) => {
         &[
             b"distribution".as_ref(),
-            $distribution.admin.as_ref(),
+            $distribution.creator.as_ref(),
             &$distribution.index.to_le_bytes(),
             &[$distribution.bump],
         ]
@@ -80,3 +704,68 @@ macro_rules! distribution_seeds {
 }
 
 pub use distribution_seeds;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::LEGACY_PARTICIPANT_SIZE;
+
+    macro_rules! offset_of {
+        ($ty:ty, $field:ident) => {{
+            let value = std::mem::MaybeUninit::<$ty>::uninit();
+            let base = value.as_ptr();
+            let field = unsafe { std::ptr::addr_of!((*base).$field) };
+            field as usize - base as usize
+        }};
+    }
+
+    #[test]
+    fn first_version_offsets_are_stable() {
+        assert_eq!(offset_of!(Distribution, admin), 0);
+        assert_eq!(offset_of!(Distribution, registrar), 32);
+        assert_eq!(offset_of!(Distribution, mint), 64);
+        assert_eq!(offset_of!(Distribution, vault), 96);
+        assert_eq!(offset_of!(Distribution, index), 128);
+        assert_eq!(offset_of!(Distribution, registration_end_ts), 136);
+        assert_eq!(offset_of!(Distribution, weight_ts), 144);
+        assert_eq!(offset_of!(Distribution, participant_total_weight), 152);
+        assert_eq!(
+            offset_of!(Distribution, legacy_total_amount_to_distribute),
+            168
+        );
+        assert_eq!(offset_of!(Distribution, time_offset), 176);
+        assert_eq!(offset_of!(Distribution, participant_count), 184);
+        assert_eq!(offset_of!(Distribution, claim_count), 188);
+        assert_eq!(offset_of!(Distribution, bump), 192);
+        assert_eq!(
+            offset_of!(Distribution, claim_lockup_required),
+            LEGACY_DISTRIBUTION_SIZE
+        );
+
+        assert_eq!(offset_of!(Participant, distribution), 0);
+        assert_eq!(offset_of!(Participant, voter), 32);
+        assert_eq!(offset_of!(Participant, voter_authority), 64);
+        assert_eq!(offset_of!(Participant, payer), 96);
+        assert_eq!(offset_of!(Participant, weight), 128);
+        assert_eq!(
+            offset_of!(Participant, prior_weight),
+            LEGACY_PARTICIPANT_SIZE
+        );
+    }
+
+    #[cfg(any(feature = "localnet", feature = "test-bpf"))]
+    #[test]
+    fn time_offset_applies_in_test_builds() {
+        assert_eq!(offset_unix_timestamp(1000, 0), 1000);
+        assert_eq!(offset_unix_timestamp(1000, 500), 1500);
+        assert_eq!(offset_unix_timestamp(1000, -500), 500);
+    }
+
+    #[cfg(not(any(feature = "localnet", feature = "test-bpf")))]
+    #[test]
+    fn time_offset_ignored_in_mainnet_builds() {
+        assert_eq!(offset_unix_timestamp(1000, 0), 1000);
+        assert_eq!(offset_unix_timestamp(1000, 500), 1000);
+        assert_eq!(offset_unix_timestamp(1000, -500), 1000);
+    }
+}
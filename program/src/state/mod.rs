@@ -1,5 +1,15 @@
+mod claim_bitmap;
 mod distribution;
 mod participant;
+mod reward_vault;
+mod rewards;
+mod weight_checkpoint;
+mod weight_curve;
 
+pub use claim_bitmap::*;
 pub use distribution::*;
 pub use participant::*;
+pub use reward_vault::*;
+pub use rewards::*;
+pub use weight_checkpoint::*;
+pub use weight_curve::*;
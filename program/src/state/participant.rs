@@ -1,6 +1,14 @@
+use crate::state::MAX_REWARD_VAULTS;
 use anchor_lang::prelude::*;
 
+/// The size of Participant accounts created by the first program version,
+/// without the discriminator.
+pub const LEGACY_PARTICIPANT_SIZE: usize = 4 * 32 + 8;
+
 /// User account for minting voting rights.
+///
+/// The first LEGACY_PARTICIPANT_SIZE bytes keep the layout of the first
+/// program version. The rest was appended, see MigrateParticipant.
 #[account(zero_copy)]
 pub struct Participant {
     pub distribution: Pubkey,
@@ -9,6 +17,80 @@ pub struct Participant {
     // The account that funded this Participant account
     pub payer: Pubkey,
     pub weight: u64,
+
+    /// epoch distributions only: the weight that applies to epochs before
+    /// weight_change_epoch, see Distribution::set_participant_weight
+    pub prior_weight: u64,
+
+    /// the participant's share of the distribution's vault
+    pub rewards: ParticipantRewards,
+    /// the participant's shares of the distribution's reward vaults, in the
+    /// order of Distribution::reward_vaults
+    pub reward_vault_rewards: [ParticipantRewards; MAX_REWARD_VAULTS],
+
+    /// the time of the last claim, zero if not claimed yet
+    pub claimed_ts: u64,
+    /// nonzero once a claim was made after vesting finished
+    /// kept participants can still claim deposits that arrive later
+    pub claimed: u8,
+
+    /// epoch distributions only: the epoch from which weight applies
+    pub weight_change_epoch: u32,
+    /// epoch distributions only: the first epoch that wasn't claimed yet
+    pub next_claim_epoch: u32,
+
+    /// the distribution's weight_revision when weight was last evaluated, see
+    /// Distribution::participant_weight_is_stale
+    pub weight_revision: u32,
+
+    /// the voter's deposit entry that ClaimAndLock deposits into, only valid
+    /// if has_lockup_deposit_entry is nonzero
+    pub lockup_deposit_entry_index: u8,
+    pub has_lockup_deposit_entry: u8,
+
+    pub reserved: [u8; 9],
 }
-const_assert!(std::mem::size_of::<Participant>() == 4 * 32 + 8);
+const_assert!(
+    std::mem::size_of::<Participant>()
+        == 4 * 32 + 3 * 8 + (1 + MAX_REWARD_VAULTS) * (16 + 2 * 8) + 1 + 3 * 4 + 2 + 9
+);
 const_assert!(std::mem::size_of::<Participant>() % 8 == 0);
+
+impl Participant {
+    pub fn is_claimed(&self) -> bool {
+        self.claimed != 0
+    }
+
+    /// The deposit entry created by the participant's first ClaimAndLock.
+    pub fn lockup_deposit_entry(&self) -> Option<u8> {
+        (self.has_lockup_deposit_entry != 0).then(|| self.lockup_deposit_entry_index)
+    }
+
+    pub fn set_lockup_deposit_entry(&mut self, index: u8) {
+        self.lockup_deposit_entry_index = index;
+        self.has_lockup_deposit_entry = 1;
+    }
+
+    /// The weight that applies in `epoch` of an epoch distribution.
+    pub fn epoch_weight(&self, epoch: u32) -> u64 {
+        if epoch >= self.weight_change_epoch {
+            self.weight
+        } else {
+            self.prior_weight
+        }
+    }
+}
+
+/// A participant's share of one of the distribution's vaults.
+#[zero_copy]
+#[derive(Default)]
+pub struct ParticipantRewards {
+    /// the vault's reward_per_weight up to which earned_amount is settled,
+    /// see Rewards::settle
+    pub reward_debt: u128,
+    /// the amount of tokens earned so far
+    pub earned_amount: u64,
+    /// the amount of tokens transferred by claims so far
+    pub claimed_amount: u64,
+}
+const_assert!(std::mem::size_of::<ParticipantRewards>() == 16 + 2 * 8);
@@ -0,0 +1,14 @@
+use crate::state::Rewards;
+use anchor_lang::prelude::*;
+
+/// An additional reward token account of a distribution, see AddRewardVault.
+#[zero_copy]
+#[derive(Default)]
+pub struct RewardVault {
+    pub mint: Pubkey,
+    /// the distribution's associated token account for mint
+    pub vault: Pubkey,
+
+    pub rewards: Rewards,
+}
+const_assert!(std::mem::size_of::<RewardVault>() == 2 * 32 + 16 + 2 * 8);
@@ -0,0 +1,146 @@
+use crate::state::ParticipantRewards;
+use anchor_lang::prelude::*;
+
+/// Scale of Rewards::reward_per_weight.
+pub const REWARD_PER_WEIGHT_SCALE: u128 = 1_000_000_000_000;
+
+/// Accounting of how the tokens in one of a distribution's vaults are split
+/// among the participants.
+///
+/// Tokens that arrive in the vault are added to a cumulative reward per unit
+/// of weight. Participants track the reward_per_weight up to which their
+/// earnings were settled, see ParticipantRewards.
+#[zero_copy]
+#[derive(Default)]
+pub struct Rewards {
+    /// cumulative amount of tokens per unit of participant weight, scaled by
+    /// REWARD_PER_WEIGHT_SCALE
+    pub reward_per_weight: u128,
+
+    /// the amount of tokens that arrived in the vault and were added to
+    /// reward_per_weight
+    pub total_amount_to_distribute: u64,
+
+    /// the amount of tokens transferred out of the vault by claims
+    pub claimed_amount: u64,
+}
+const_assert!(std::mem::size_of::<Rewards>() == 16 + 2 * 8);
+
+impl Rewards {
+    /// Distributes the tokens that arrived in the vault since the last update.
+    ///
+    /// Whatever is in the vault and wasn't claimed yet must either have been
+    /// accounted for before or be new.
+    pub fn update(&mut self, vault_amount: u64, participant_total_weight: u128) {
+        if participant_total_weight == 0 {
+            return;
+        }
+        let seen_amount = vault_amount.checked_add(self.claimed_amount).unwrap();
+        let new_amount = seen_amount.saturating_sub(self.total_amount_to_distribute);
+        if new_amount == 0 {
+            return;
+        }
+        // This rounds down, meaning not all tokens may be fully distributed.
+        let increment = (new_amount as u128)
+            .checked_mul(REWARD_PER_WEIGHT_SCALE)
+            .unwrap()
+            / participant_total_weight;
+        self.reward_per_weight = self.reward_per_weight.checked_add(increment).unwrap();
+        self.total_amount_to_distribute = seen_amount;
+    }
+
+    /// Adds what a participant with `weight` earned since their last
+    /// settlement to their earned_amount.
+    ///
+    /// Must be called before changing a participant's weight.
+    pub fn settle(&self, participant: &mut ParticipantRewards, weight: u64) {
+        let earned = u64::try_from(
+            (weight as u128)
+                .checked_mul(self.reward_per_weight - participant.reward_debt)
+                .unwrap()
+                / REWARD_PER_WEIGHT_SCALE,
+        )
+        .unwrap();
+        participant.earned_amount = participant.earned_amount.checked_add(earned).unwrap();
+        participant.reward_debt = self.reward_per_weight;
+    }
+
+    /// Records a transfer of `amount` out of the vault.
+    pub fn record_claim(&mut self, amount: u64) {
+        self.claimed_amount = self.claimed_amount.checked_add(amount).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn earned(rewards: &Rewards, participant: &mut ParticipantRewards, weight: u64) -> u64 {
+        rewards.settle(participant, weight);
+        participant.earned_amount
+    }
+
+    #[test]
+    fn late_deposits_are_shared_by_weight() {
+        let mut rewards = Rewards::default();
+        let (weight0, weight1) = (1_000, 3_000);
+        let total_weight = (weight0 + weight1) as u128;
+        let mut participant0 = ParticipantRewards::default();
+        let mut participant1 = ParticipantRewards::default();
+
+        rewards.update(400, total_weight);
+        assert_eq!(earned(&rewards, &mut participant0, weight0), 100);
+
+        // participant 0 claims, then the vault is topped up
+        rewards.record_claim(100);
+        rewards.update(300 + 800, total_weight);
+        assert_eq!(rewards.total_amount_to_distribute, 1_200);
+        assert_eq!(earned(&rewards, &mut participant0, weight0), 300);
+        assert_eq!(earned(&rewards, &mut participant1, weight1), 900);
+
+        // nothing new arrived
+        rewards.update(1_100, total_weight);
+        assert_eq!(earned(&rewards, &mut participant0, weight0), 300);
+    }
+
+    #[test]
+    fn weight_changes_only_affect_later_deposits() {
+        let mut rewards = Rewards::default();
+        let mut participant0 = ParticipantRewards::default();
+        let mut participant1 = ParticipantRewards::default();
+
+        rewards.update(1_000, 2_000);
+        assert_eq!(earned(&rewards, &mut participant0, 1_000), 500);
+
+        // participant 0 loses half their weight
+        rewards.update(1_000 + 1_500, 1_500);
+        assert_eq!(earned(&rewards, &mut participant0, 500), 500 + 500);
+        assert_eq!(earned(&rewards, &mut participant1, 1_000), 500 + 1_000);
+    }
+
+    #[test]
+    fn rounding_never_overpays() {
+        let mut rewards = Rewards::default();
+        let weights = [1u64, 7, 13];
+        let total_weight: u64 = weights.iter().sum();
+        let mut participants = [ParticipantRewards::default(); 3];
+
+        for vault_amount in [10, 1_000, 1_337] {
+            rewards.update(vault_amount, total_weight as u128);
+            let total_earned: u64 = weights
+                .iter()
+                .zip(participants.iter_mut())
+                .map(|(weight, participant)| earned(&rewards, participant, *weight))
+                .sum();
+            assert!(total_earned <= vault_amount);
+        }
+    }
+
+    #[test]
+    fn no_weight_distributes_nothing() {
+        let mut rewards = Rewards::default();
+        rewards.update(1_000, 0);
+        assert_eq!(rewards.reward_per_weight, 0);
+        assert_eq!(rewards.total_amount_to_distribute, 0);
+    }
+}
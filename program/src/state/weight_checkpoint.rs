@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+/// A time at which locked vote weight is evaluated, see SetWeightCheckpoints.
+#[zero_copy]
+#[derive(Default)]
+pub struct WeightCheckpoint {
+    pub ts: u64,
+    /// the part of the voter's weight at ts that counts, in basis points
+    pub factor_bps: u64,
+}
+const_assert!(std::mem::size_of::<WeightCheckpoint>() == 2 * 8);
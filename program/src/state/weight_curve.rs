@@ -0,0 +1,169 @@
+/// Number of fractional bits in the results of the sublinear curves.
+pub const WEIGHT_CURVE_FRACTION_BITS: u32 = 16;
+
+/// Transformation of locked vote weight into participant weight.
+///
+/// Sublinear curves spread the distribution across more holders. Their
+/// results are fixed point numbers with WEIGHT_CURVE_FRACTION_BITS fractional
+/// bits, computed with integer math only.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WeightCurve {
+    /// weight = locked vote weight
+    Linear = 0,
+    /// weight = sqrt(locked vote weight)
+    Sqrt = 1,
+    /// weight = log2(1 + locked vote weight)
+    Log2 = 2,
+}
+
+impl WeightCurve {
+    pub fn from_u8(curve: u8) -> Option<Self> {
+        match curve {
+            0 => Some(WeightCurve::Linear),
+            1 => Some(WeightCurve::Sqrt),
+            2 => Some(WeightCurve::Log2),
+            _ => None,
+        }
+    }
+
+    pub fn apply(self, locked_weight: u64) -> u64 {
+        match self {
+            WeightCurve::Linear => locked_weight,
+            WeightCurve::Sqrt => sqrt_fixed(locked_weight),
+            WeightCurve::Log2 => log2_fixed(locked_weight as u128 + 1),
+        }
+    }
+}
+
+/// sqrt(x), rounded down, with WEIGHT_CURVE_FRACTION_BITS fractional bits.
+fn sqrt_fixed(x: u64) -> u64 {
+    // sqrt(x * 2^(2*F)) = sqrt(x) * 2^F, fits in 32 + F bits
+    isqrt((x as u128) << (2 * WEIGHT_CURVE_FRACTION_BITS)) as u64
+}
+
+/// Integer square root, rounded down.
+fn isqrt(x: u128) -> u128 {
+    if x < 2 {
+        return x;
+    }
+    // Newton's method, starting from a power of two that is >= sqrt(x)
+    let bits = 128 - x.leading_zeros();
+    let mut root = 1u128 << ((bits + 1) / 2);
+    loop {
+        let next = (root + x / root) / 2;
+        if next >= root {
+            return root;
+        }
+        root = next;
+    }
+}
+
+/// log2(x) for x >= 1, rounded down, with WEIGHT_CURVE_FRACTION_BITS
+/// fractional bits.
+fn log2_fixed(x: u128) -> u64 {
+    assert!(x >= 1);
+    let integer_part = 127 - x.leading_zeros();
+
+    // Normalize x to y in [1, 2) with 62 fractional bits. Then, for each
+    // fractional bit of the result, square y: if y >= 2, the bit is set and
+    // y is halved.
+    const ONE: u128 = 1 << 62;
+    let mut y = if integer_part <= 62 {
+        x << (62 - integer_part)
+    } else {
+        x >> (integer_part - 62)
+    };
+    let mut result = (integer_part as u64) << WEIGHT_CURVE_FRACTION_BITS;
+    for bit in (0..WEIGHT_CURVE_FRACTION_BITS).rev() {
+        y = y * y / ONE;
+        if y >= 2 * ONE {
+            y /= 2;
+            result |= 1 << bit;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE: f64 = (1u64 << WEIGHT_CURVE_FRACTION_BITS) as f64;
+
+    fn sample_weights() -> Vec<u64> {
+        let mut weights: Vec<u64> = (0..10_000).collect();
+        for shift in 0..64 {
+            let power = 1u64 << shift;
+            weights.extend([power - 1, power, power + 1]);
+        }
+        weights.extend([u64::MAX - 1, u64::MAX]);
+        weights
+    }
+
+    #[test]
+    fn curve_from_u8() {
+        assert_eq!(WeightCurve::from_u8(0), Some(WeightCurve::Linear));
+        assert_eq!(WeightCurve::from_u8(1), Some(WeightCurve::Sqrt));
+        assert_eq!(WeightCurve::from_u8(2), Some(WeightCurve::Log2));
+        assert_eq!(WeightCurve::from_u8(3), None);
+    }
+
+    #[test]
+    fn linear_is_identity() {
+        for weight in sample_weights() {
+            assert_eq!(WeightCurve::Linear.apply(weight), weight);
+        }
+    }
+
+    #[test]
+    fn sqrt_rounds_down_exactly() {
+        for weight in sample_weights() {
+            let root = WeightCurve::Sqrt.apply(weight) as u128;
+            let scaled = (weight as u128) << (2 * WEIGHT_CURVE_FRACTION_BITS);
+            assert!(root * root <= scaled, "weight {}", weight);
+            assert!((root + 1) * (root + 1) > scaled, "weight {}", weight);
+        }
+        assert_eq!(WeightCurve::Sqrt.apply(0), 0);
+        assert_eq!(WeightCurve::Sqrt.apply(1), 1 << WEIGHT_CURVE_FRACTION_BITS);
+        assert_eq!(
+            WeightCurve::Sqrt.apply(1_000_000),
+            1000 << WEIGHT_CURVE_FRACTION_BITS
+        );
+    }
+
+    #[test]
+    fn log2_matches_float() {
+        for weight in sample_weights() {
+            let fixed = WeightCurve::Log2.apply(weight) as f64 / ONE;
+            let float = (weight as f64 + 1.0).log2();
+            // truncation loses at most one fractional bit, plus float error
+            assert!(
+                fixed <= float + 1e-9 && float - fixed < 2.0 / ONE,
+                "weight {}: {} vs {}",
+                weight,
+                fixed,
+                float
+            );
+        }
+        assert_eq!(WeightCurve::Log2.apply(0), 0);
+        assert_eq!(WeightCurve::Log2.apply(1), 1 << WEIGHT_CURVE_FRACTION_BITS);
+        assert_eq!(
+            WeightCurve::Log2.apply(1023),
+            10 << WEIGHT_CURVE_FRACTION_BITS
+        );
+        assert_eq!(
+            WeightCurve::Log2.apply(u64::MAX),
+            64 << WEIGHT_CURVE_FRACTION_BITS
+        );
+    }
+
+    #[test]
+    fn curves_are_monotonic() {
+        for curve in [WeightCurve::Linear, WeightCurve::Sqrt, WeightCurve::Log2] {
+            let mut weights = sample_weights();
+            weights.sort_unstable();
+            let values: Vec<u64> = weights.iter().map(|w| curve.apply(*w)).collect();
+            assert!(values.windows(2).all(|v| v[0] <= v[1]), "{:?}", curve);
+        }
+    }
+}
@@ -7,6 +7,25 @@ use solana_sdk::signature::{Keypair, Signer};
 use super::client::*;
 use distribute_by_locked_vote_weight::state::*;
 
+/// Remaining accounts for the distribution's reward vaults: the vaults paired
+/// with the associated token accounts of `target_owner` for their mints.
+fn reward_vault_metas(distribution: &Distribution, target_owner: &Pubkey) -> Vec<AccountMeta> {
+    distribution
+        .used_reward_vaults()
+        .iter()
+        .flat_map(|reward_vault| {
+            let target = spl_associated_token_account::get_associated_token_address(
+                target_owner,
+                &reward_vault.mint,
+            );
+            [
+                AccountMeta::new(reward_vault.vault, false),
+                AccountMeta::new(target, false),
+            ]
+        })
+        .collect()
+}
+
 //
 // a struct for each instruction along with its
 // ClientInstruction impl
@@ -16,6 +35,9 @@ pub struct CreateDistributionInstruction<'keypair> {
     pub index: u64,
     pub end_ts: u64,
     pub weight_ts: u64,
+    pub claim_end_ts: u64,
+    pub keep_participants: bool,
+    pub min_weight: u64,
 
     pub registrar: Pubkey,
     pub mint: Pubkey,
@@ -35,6 +57,9 @@ impl<'keypair> ClientInstruction for CreateDistributionInstruction<'keypair> {
             index: self.index,
             end_ts: self.end_ts,
             weight_ts: self.weight_ts,
+            claim_end_ts: self.claim_end_ts,
+            keep_participants: self.keep_participants,
+            min_weight: self.min_weight,
         };
 
         let distribution = Pubkey::find_program_address(
@@ -71,41 +96,67 @@ impl<'keypair> ClientInstruction for CreateDistributionInstruction<'keypair> {
     }
 }
 
-pub struct CreateParticipantInstruction<'keypair> {
+pub struct SetVestingInstruction<'keypair> {
     pub distribution: Pubkey,
-    pub voter: Pubkey,
-    pub payer: &'keypair Keypair,
+    pub admin: &'keypair Keypair,
+    pub vesting_start_ts: u64,
+    pub vesting_cliff_ts: u64,
+    pub vesting_end_ts: u64,
 }
 #[async_trait::async_trait(?Send)]
-impl<'keypair> ClientInstruction for CreateParticipantInstruction<'keypair> {
-    type Accounts = distribute_by_locked_vote_weight::accounts::CreateParticipant;
-    type Instruction = distribute_by_locked_vote_weight::instruction::CreateParticipant;
+impl<'keypair> ClientInstruction for SetVestingInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::SetVesting;
+    type Instruction = distribute_by_locked_vote_weight::instruction::SetVesting;
     async fn to_instruction(
         &self,
-        account_loader: impl ClientAccountLoader + 'async_trait,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
         let program_id = distribute_by_locked_vote_weight::id();
-        let instruction = Self::Instruction {};
+        let instruction = Self::Instruction {
+            vesting_start_ts: self.vesting_start_ts,
+            vesting_cliff_ts: self.vesting_cliff_ts,
+            vesting_end_ts: self.vesting_end_ts,
+        };
 
-        let participant = Pubkey::find_program_address(
-            &[
-                self.distribution.as_ref(),
-                b"participant".as_ref(),
-                self.voter.as_ref(),
-            ],
-            &program_id,
-        )
-        .0;
-        let distribution: Distribution = account_loader.load(&self.distribution).await.unwrap();
+        let accounts = Self::Accounts {
+            distribution: self.distribution,
+            admin: self.admin.pubkey(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.admin]
+    }
+}
+
+pub struct SetClaimLockupInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub admin: &'keypair Keypair,
+    pub lockup_kind: voter_stake_registry::state::LockupKind,
+    pub lockup_periods: u32,
+    pub lockup_required: bool,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for SetClaimLockupInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::SetClaimLockup;
+    type Instruction = distribute_by_locked_vote_weight::instruction::SetClaimLockup;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {
+            lockup_kind: self.lockup_kind as u8,
+            lockup_periods: self.lockup_periods,
+            lockup_required: self.lockup_required,
+        };
 
         let accounts = Self::Accounts {
             distribution: self.distribution,
-            participant,
-            voter: self.voter,
-            registrar: distribution.registrar,
-            payer: self.payer.pubkey(),
-            system_program: System::id(),
-            rent: sysvar::rent::Rent::id(),
+            admin: self.admin.pubkey(),
         };
 
         let instruction = make_instruction(program_id, &accounts, instruction);
@@ -113,35 +164,65 @@ impl<'keypair> ClientInstruction for CreateParticipantInstruction<'keypair> {
     }
 
     fn signers(&self) -> Vec<&Keypair> {
-        vec![self.payer]
+        vec![self.admin]
     }
 }
 
-pub struct UpdateParticipantInstruction {
-    pub participant: Pubkey,
+pub struct SetWeightCapInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub admin: &'keypair Keypair,
+    pub max_weight_per_participant: u64,
+    pub in_bps: bool,
 }
 #[async_trait::async_trait(?Send)]
-impl ClientInstruction for UpdateParticipantInstruction {
-    type Accounts = distribute_by_locked_vote_weight::accounts::UpdateParticipant;
-    type Instruction = distribute_by_locked_vote_weight::instruction::UpdateParticipant;
+impl<'keypair> ClientInstruction for SetWeightCapInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::SetWeightCap;
+    type Instruction = distribute_by_locked_vote_weight::instruction::SetWeightCap;
     async fn to_instruction(
         &self,
-        account_loader: impl ClientAccountLoader + 'async_trait,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
         let program_id = distribute_by_locked_vote_weight::id();
-        let instruction = Self::Instruction {};
+        let instruction = Self::Instruction {
+            max_weight_per_participant: self.max_weight_per_participant,
+            in_bps: self.in_bps,
+        };
 
-        let participant: Participant = account_loader.load(&self.participant).await.unwrap();
-        let distribution: Distribution = account_loader
-            .load(&participant.distribution)
-            .await
-            .unwrap();
+        let accounts = Self::Accounts {
+            distribution: self.distribution,
+            admin: self.admin.pubkey(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.admin]
+    }
+}
+
+pub struct SetWeightCurveInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub admin: &'keypair Keypair,
+    pub weight_curve: u8,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for SetWeightCurveInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::SetWeightCurve;
+    type Instruction = distribute_by_locked_vote_weight::instruction::SetWeightCurve;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {
+            weight_curve: self.weight_curve,
+        };
 
         let accounts = Self::Accounts {
-            distribution: participant.distribution,
-            participant: self.participant,
-            voter: participant.voter,
-            registrar: distribution.registrar,
+            distribution: self.distribution,
+            admin: self.admin.pubkey(),
         };
 
         let instruction = make_instruction(program_id, &accounts, instruction);
@@ -149,26 +230,28 @@ impl ClientInstruction for UpdateParticipantInstruction {
     }
 
     fn signers(&self) -> Vec<&Keypair> {
-        vec![]
+        vec![self.admin]
     }
 }
 
-pub struct SetTimeOffsetInstruction<'keypair> {
+pub struct SetDepositFilterInstruction<'keypair> {
     pub distribution: Pubkey,
     pub admin: &'keypair Keypair,
-    pub time_offset: i64,
+    pub lockup_kinds: u8,
+    pub min_lockup_end_ts: u64,
 }
 #[async_trait::async_trait(?Send)]
-impl<'keypair> ClientInstruction for SetTimeOffsetInstruction<'keypair> {
-    type Accounts = distribute_by_locked_vote_weight::accounts::SetTimeOffset;
-    type Instruction = distribute_by_locked_vote_weight::instruction::SetTimeOffset;
+impl<'keypair> ClientInstruction for SetDepositFilterInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::SetDepositFilter;
+    type Instruction = distribute_by_locked_vote_weight::instruction::SetDepositFilter;
     async fn to_instruction(
         &self,
         _account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
         let program_id = distribute_by_locked_vote_weight::id();
         let instruction = Self::Instruction {
-            time_offset: self.time_offset,
+            lockup_kinds: self.lockup_kinds,
+            min_lockup_end_ts: self.min_lockup_end_ts,
         };
 
         let accounts = Self::Accounts {
@@ -185,37 +268,63 @@ impl<'keypair> ClientInstruction for SetTimeOffsetInstruction<'keypair> {
     }
 }
 
-pub struct ClaimInstruction<'keypair> {
-    pub participant: Pubkey,
-    pub voter_authority: &'keypair Keypair,
-    pub target_token: Pubkey,
-    pub payer: Pubkey,
+pub struct SetVotingMintsInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub admin: &'keypair Keypair,
+    pub voting_mints: Vec<u8>,
 }
 #[async_trait::async_trait(?Send)]
-impl<'keypair> ClientInstruction for ClaimInstruction<'keypair> {
-    type Accounts = distribute_by_locked_vote_weight::accounts::Claim;
-    type Instruction = distribute_by_locked_vote_weight::instruction::Claim;
+impl<'keypair> ClientInstruction for SetVotingMintsInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::SetVotingMints;
+    type Instruction = distribute_by_locked_vote_weight::instruction::SetVotingMints;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
         let program_id = distribute_by_locked_vote_weight::id();
-        let instruction = Self::Instruction {};
+        let instruction = Self::Instruction {
+            voting_mints: self.voting_mints.clone(),
+        };
 
-        let participant: Participant = account_loader.load(&self.participant).await.unwrap();
-        let distribution: Distribution = account_loader
-            .load(&participant.distribution)
-            .await
-            .unwrap();
+        let distribution: Distribution = account_loader.load(&self.distribution).await.unwrap();
+        let accounts = Self::Accounts {
+            distribution: self.distribution,
+            admin: self.admin.pubkey(),
+            registrar: distribution.registrar,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.admin]
+    }
+}
+
+pub struct SetWeightCheckpointsInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub admin: &'keypair Keypair,
+    pub weight_ts: Vec<u64>,
+    pub factors_bps: Vec<u16>,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for SetWeightCheckpointsInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::SetWeightCheckpoints;
+    type Instruction = distribute_by_locked_vote_weight::instruction::SetWeightCheckpoints;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {
+            weight_ts: self.weight_ts.clone(),
+            factors_bps: self.factors_bps.clone(),
+        };
 
         let accounts = Self::Accounts {
-            distribution: participant.distribution,
-            participant: self.participant,
-            vault: distribution.vault,
-            target_token: self.target_token,
-            voter_authority: self.voter_authority.pubkey(),
-            payer: self.payer,
-            token_program: Token::id(),
+            distribution: self.distribution,
+            admin: self.admin.pubkey(),
         };
 
         let instruction = make_instruction(program_id, &accounts, instruction);
@@ -223,42 +332,65 @@ impl<'keypair> ClientInstruction for ClaimInstruction<'keypair> {
     }
 
     fn signers(&self) -> Vec<&Keypair> {
-        vec![self.voter_authority]
+        vec![self.admin]
     }
 }
 
-pub struct LogInfoInstruction {
+pub struct SetEpochsInstruction<'keypair> {
     pub distribution: Pubkey,
-    pub voter: Pubkey,
+    pub admin: &'keypair Keypair,
+    pub epoch_duration: u64,
 }
 #[async_trait::async_trait(?Send)]
-impl ClientInstruction for LogInfoInstruction {
-    type Accounts = distribute_by_locked_vote_weight::accounts::LogInfo;
-    type Instruction = distribute_by_locked_vote_weight::instruction::LogInfo;
+impl<'keypair> ClientInstruction for SetEpochsInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::SetEpochs;
+    type Instruction = distribute_by_locked_vote_weight::instruction::SetEpochs;
     async fn to_instruction(
         &self,
-        account_loader: impl ClientAccountLoader + 'async_trait,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
         let program_id = distribute_by_locked_vote_weight::id();
-        let instruction = Self::Instruction {};
+        let instruction = Self::Instruction {
+            epoch_duration: self.epoch_duration,
+        };
 
-        let participant = Pubkey::find_program_address(
-            &[
-                self.distribution.as_ref(),
-                b"participant".as_ref(),
-                self.voter.as_ref(),
-            ],
-            &program_id,
-        )
-        .0;
-        let distribution: Distribution = account_loader.load(&self.distribution).await.unwrap();
+        let accounts = Self::Accounts {
+            distribution: self.distribution,
+            admin: self.admin.pubkey(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.admin]
+    }
+}
+
+pub struct SetMerkleRootInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub admin: &'keypair Keypair,
+    pub merkle_root: [u8; 32],
+    pub leaf_count: u32,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for SetMerkleRootInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::SetMerkleRoot;
+    type Instruction = distribute_by_locked_vote_weight::instruction::SetMerkleRoot;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {
+            merkle_root: self.merkle_root,
+            leaf_count: self.leaf_count,
+        };
 
         let accounts = Self::Accounts {
             distribution: self.distribution,
-            vault: distribution.vault,
-            participant,
-            voter: self.voter,
-            registrar: distribution.registrar,
+            admin: self.admin.pubkey(),
         };
 
         let instruction = make_instruction(program_id, &accounts, instruction);
@@ -266,6 +398,1041 @@ impl ClientInstruction for LogInfoInstruction {
     }
 
     fn signers(&self) -> Vec<&Keypair> {
-        vec![]
+        vec![self.admin]
+    }
+}
+
+pub struct SetAdminInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub admin: &'keypair Keypair,
+    pub new_admin: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for SetAdminInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::SetAdmin;
+    type Instruction = distribute_by_locked_vote_weight::instruction::SetAdmin;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {
+            new_admin: self.new_admin,
+        };
+
+        let accounts = Self::Accounts {
+            distribution: self.distribution,
+            admin: self.admin.pubkey(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.admin]
+    }
+}
+
+pub struct AcceptAdminInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub pending_admin: &'keypair Keypair,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for AcceptAdminInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::AcceptAdmin;
+    type Instruction = distribute_by_locked_vote_weight::instruction::AcceptAdmin;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {};
+
+        let accounts = Self::Accounts {
+            distribution: self.distribution,
+            pending_admin: self.pending_admin.pubkey(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.pending_admin]
+    }
+}
+
+pub struct SetRolesInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub admin: &'keypair Keypair,
+    pub funder: Pubkey,
+    pub canceller: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for SetRolesInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::SetRoles;
+    type Instruction = distribute_by_locked_vote_weight::instruction::SetRoles;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {
+            funder: self.funder,
+            canceller: self.canceller,
+        };
+
+        let accounts = Self::Accounts {
+            distribution: self.distribution,
+            admin: self.admin.pubkey(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.admin]
+    }
+}
+
+pub struct SetClaimEndInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub admin: &'keypair Keypair,
+    pub claim_end_ts: u64,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for SetClaimEndInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::SetClaimEnd;
+    type Instruction = distribute_by_locked_vote_weight::instruction::SetClaimEnd;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {
+            claim_end_ts: self.claim_end_ts,
+        };
+
+        let accounts = Self::Accounts {
+            distribution: self.distribution,
+            admin: self.admin.pubkey(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.admin]
+    }
+}
+
+pub struct UpdateDistributionConfigInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub admin: &'keypair Keypair,
+    pub registration_end_ts: u64,
+    pub weight_ts: u64,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for UpdateDistributionConfigInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::UpdateDistributionConfig;
+    type Instruction = distribute_by_locked_vote_weight::instruction::UpdateDistributionConfig;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {
+            registration_end_ts: self.registration_end_ts,
+            weight_ts: self.weight_ts,
+        };
+
+        let accounts = Self::Accounts {
+            distribution: self.distribution,
+            admin: self.admin.pubkey(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.admin]
+    }
+}
+
+pub struct SetVerificationWindowInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub admin: &'keypair Keypair,
+    pub verification_duration: u64,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for SetVerificationWindowInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::SetVerificationWindow;
+    type Instruction = distribute_by_locked_vote_weight::instruction::SetVerificationWindow;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {
+            verification_duration: self.verification_duration,
+        };
+
+        let accounts = Self::Accounts {
+            distribution: self.distribution,
+            admin: self.admin.pubkey(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.admin]
+    }
+}
+
+pub struct SetFundingRestrictionInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub authority: &'keypair Keypair,
+    pub registration_only: bool,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for SetFundingRestrictionInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::SetFundingRestriction;
+    type Instruction = distribute_by_locked_vote_weight::instruction::SetFundingRestriction;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {
+            registration_only: self.registration_only,
+        };
+
+        let accounts = Self::Accounts {
+            distribution: self.distribution,
+            authority: self.authority.pubkey(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.authority]
+    }
+}
+
+pub struct FundDistributionInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub funder: &'keypair Keypair,
+    pub funder_token: Pubkey,
+    pub amount: u64,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for FundDistributionInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::FundDistribution;
+    type Instruction = distribute_by_locked_vote_weight::instruction::FundDistribution;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {
+            amount: self.amount,
+        };
+
+        let distribution: Distribution = account_loader.load(&self.distribution).await.unwrap();
+
+        let accounts = Self::Accounts {
+            distribution: self.distribution,
+            vault: distribution.vault,
+            funder_token: self.funder_token,
+            funder: self.funder.pubkey(),
+            token_program: Token::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.funder]
+    }
+}
+
+pub struct AddRewardVaultInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub authority: &'keypair Keypair,
+    pub mint: Pubkey,
+    pub payer: &'keypair Keypair,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for AddRewardVaultInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::AddRewardVault;
+    type Instruction = distribute_by_locked_vote_weight::instruction::AddRewardVault;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {};
+
+        let vault = spl_associated_token_account::get_associated_token_address(
+            &self.distribution,
+            &self.mint,
+        );
+
+        let accounts = Self::Accounts {
+            distribution: self.distribution,
+            authority: self.authority.pubkey(),
+            mint: self.mint,
+            vault,
+            payer: self.payer.pubkey(),
+            system_program: System::id(),
+            token_program: Token::id(),
+            associated_token_program: spl_associated_token_account::id(),
+            rent: sysvar::rent::Rent::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.payer, self.authority]
+    }
+}
+
+pub struct CreateParticipantInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub voter: Pubkey,
+    pub payer: &'keypair Keypair,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for CreateParticipantInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::CreateParticipant;
+    type Instruction = distribute_by_locked_vote_weight::instruction::CreateParticipant;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {};
+
+        let participant = Pubkey::find_program_address(
+            &[
+                self.distribution.as_ref(),
+                b"participant".as_ref(),
+                self.voter.as_ref(),
+            ],
+            &program_id,
+        )
+        .0;
+        let distribution: Distribution = account_loader.load(&self.distribution).await.unwrap();
+
+        let accounts = Self::Accounts {
+            distribution: self.distribution,
+            participant,
+            voter: self.voter,
+            registrar: distribution.registrar,
+            payer: self.payer.pubkey(),
+            system_program: System::id(),
+            rent: sysvar::rent::Rent::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.payer]
+    }
+}
+
+pub struct UpdateParticipantInstruction {
+    pub participant: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for UpdateParticipantInstruction {
+    type Accounts = distribute_by_locked_vote_weight::accounts::UpdateParticipant;
+    type Instruction = distribute_by_locked_vote_weight::instruction::UpdateParticipant;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {};
+
+        let participant: Participant = account_loader.load(&self.participant).await.unwrap();
+        let distribution: Distribution = account_loader
+            .load(&participant.distribution)
+            .await
+            .unwrap();
+
+        let accounts = Self::Accounts {
+            distribution: participant.distribution,
+            participant: self.participant,
+            voter: participant.voter,
+            registrar: distribution.registrar,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![]
+    }
+}
+
+pub struct ChallengeParticipantInstruction {
+    pub participant: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for ChallengeParticipantInstruction {
+    type Accounts = distribute_by_locked_vote_weight::accounts::ChallengeParticipant;
+    type Instruction = distribute_by_locked_vote_weight::instruction::ChallengeParticipant;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {};
+
+        let participant: Participant = account_loader.load(&self.participant).await.unwrap();
+        let distribution: Distribution = account_loader
+            .load(&participant.distribution)
+            .await
+            .unwrap();
+
+        let accounts = Self::Accounts {
+            distribution: participant.distribution,
+            participant: self.participant,
+            voter: participant.voter,
+            registrar: distribution.registrar,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![]
+    }
+}
+
+pub struct RefreshParticipantInstruction {
+    pub participant: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for RefreshParticipantInstruction {
+    type Accounts = distribute_by_locked_vote_weight::accounts::RefreshParticipant;
+    type Instruction = distribute_by_locked_vote_weight::instruction::RefreshParticipant;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {};
+
+        let participant: Participant = account_loader.load(&self.participant).await.unwrap();
+        let distribution: Distribution = account_loader
+            .load(&participant.distribution)
+            .await
+            .unwrap();
+
+        let accounts = Self::Accounts {
+            distribution: participant.distribution,
+            participant: self.participant,
+            voter: participant.voter,
+            registrar: distribution.registrar,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![]
+    }
+}
+
+pub struct SetTimeOffsetInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub admin: &'keypair Keypair,
+    pub time_offset: i64,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for SetTimeOffsetInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::SetTimeOffset;
+    type Instruction = distribute_by_locked_vote_weight::instruction::SetTimeOffset;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {
+            time_offset: self.time_offset,
+        };
+
+        let accounts = Self::Accounts {
+            distribution: self.distribution,
+            admin: self.admin.pubkey(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.admin]
+    }
+}
+
+pub struct ClaimInstruction<'keypair> {
+    pub participant: Pubkey,
+    pub voter_authority: &'keypair Keypair,
+    pub target_token: Pubkey,
+    pub payer: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for ClaimInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::Claim;
+    type Instruction = distribute_by_locked_vote_weight::instruction::Claim;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {};
+
+        let participant: Participant = account_loader.load(&self.participant).await.unwrap();
+        let distribution: Distribution = account_loader
+            .load(&participant.distribution)
+            .await
+            .unwrap();
+
+        let accounts = Self::Accounts {
+            distribution: participant.distribution,
+            participant: self.participant,
+            vault: distribution.vault,
+            target_token: self.target_token,
+            voter_authority: self.voter_authority.pubkey(),
+            payer: self.payer,
+            token_program: Token::id(),
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        instruction.accounts.extend(reward_vault_metas(
+            &distribution,
+            &self.voter_authority.pubkey(),
+        ));
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.voter_authority]
+    }
+}
+
+pub struct ClaimWithProofInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub index: u32,
+    pub amount: u64,
+    pub proof: Vec<[u8; 32]>,
+    pub voter: Pubkey,
+    pub voter_authority: &'keypair Keypair,
+    pub target_token: Pubkey,
+    pub payer: &'keypair Keypair,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for ClaimWithProofInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::ClaimWithProof;
+    type Instruction = distribute_by_locked_vote_weight::instruction::ClaimWithProof;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {
+            index: self.index,
+            amount: self.amount,
+            proof: self.proof.clone(),
+        };
+
+        let distribution: Distribution = account_loader.load(&self.distribution).await.unwrap();
+        let claim_bitmap = Pubkey::find_program_address(
+            &[
+                self.distribution.as_ref(),
+                b"claim_bitmap".as_ref(),
+                &ClaimBitmap::page_for(self.index).to_le_bytes(),
+            ],
+            &program_id,
+        )
+        .0;
+
+        let accounts = Self::Accounts {
+            distribution: self.distribution,
+            claim_bitmap,
+            voter: self.voter,
+            registrar: distribution.registrar,
+            voter_authority: self.voter_authority.pubkey(),
+            vault: distribution.vault,
+            target_token: self.target_token,
+            payer: self.payer.pubkey(),
+            system_program: System::id(),
+            token_program: Token::id(),
+            rent: sysvar::rent::Rent::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.voter_authority, self.payer]
+    }
+}
+
+pub struct ReclaimRemainderInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub admin: &'keypair Keypair,
+    pub target_token: Pubkey,
+    pub sol_destination: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for ReclaimRemainderInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::ReclaimRemainder;
+    type Instruction = distribute_by_locked_vote_weight::instruction::ReclaimRemainder;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {};
+
+        let distribution: Distribution = account_loader.load(&self.distribution).await.unwrap();
+
+        let accounts = Self::Accounts {
+            distribution: self.distribution,
+            admin: self.admin.pubkey(),
+            vault: distribution.vault,
+            target_token: self.target_token,
+            sol_destination: self.sol_destination,
+            token_program: Token::id(),
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        instruction
+            .accounts
+            .extend(reward_vault_metas(&distribution, &self.admin.pubkey()));
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.admin]
+    }
+}
+
+pub struct CloseDistributionInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub admin: &'keypair Keypair,
+    pub target_token: Pubkey,
+    pub rent_receiver: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for CloseDistributionInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::CloseDistribution;
+    type Instruction = distribute_by_locked_vote_weight::instruction::CloseDistribution;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {};
+
+        let distribution: Distribution = account_loader.load(&self.distribution).await.unwrap();
+
+        let accounts = Self::Accounts {
+            distribution: self.distribution,
+            admin: self.admin.pubkey(),
+            vault: distribution.vault,
+            target_token: self.target_token,
+            rent_receiver: self.rent_receiver,
+            token_program: Token::id(),
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        instruction
+            .accounts
+            .extend(reward_vault_metas(&distribution, &self.admin.pubkey()));
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.admin]
+    }
+}
+
+pub struct ClaimForInstruction<'keypair> {
+    pub participant: Pubkey,
+    pub cranker: &'keypair Keypair,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for ClaimForInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::ClaimFor;
+    type Instruction = distribute_by_locked_vote_weight::instruction::ClaimFor;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {};
+
+        let participant: Participant = account_loader.load(&self.participant).await.unwrap();
+        let distribution: Distribution = account_loader
+            .load(&participant.distribution)
+            .await
+            .unwrap();
+        let target_token = spl_associated_token_account::get_associated_token_address(
+            &participant.voter_authority,
+            &distribution.mint,
+        );
+
+        let accounts = Self::Accounts {
+            distribution: participant.distribution,
+            participant: self.participant,
+            vault: distribution.vault,
+            mint: distribution.mint,
+            target_token,
+            voter_authority: participant.voter_authority,
+            payer: participant.payer,
+            cranker: self.cranker.pubkey(),
+            system_program: System::id(),
+            token_program: Token::id(),
+            associated_token_program: spl_associated_token_account::id(),
+            rent: sysvar::rent::Rent::id(),
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        instruction.accounts.extend(reward_vault_metas(
+            &distribution,
+            &participant.voter_authority,
+        ));
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.cranker]
+    }
+}
+
+pub struct CloseClaimedParticipantInstruction<'keypair> {
+    pub participant: Pubkey,
+    pub voter_authority: &'keypair Keypair,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for CloseClaimedParticipantInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::CloseClaimedParticipant;
+    type Instruction = distribute_by_locked_vote_weight::instruction::CloseClaimedParticipant;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {};
+
+        let participant: Participant = account_loader.load(&self.participant).await.unwrap();
+
+        let accounts = Self::Accounts {
+            participant: self.participant,
+            voter_authority: self.voter_authority.pubkey(),
+            payer: participant.payer,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.voter_authority]
+    }
+}
+
+pub struct CloseOrphanedParticipantInstruction<'keypair> {
+    pub participant: Pubkey,
+    pub voter_authority: &'keypair Keypair,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for CloseOrphanedParticipantInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::CloseOrphanedParticipant;
+    type Instruction = distribute_by_locked_vote_weight::instruction::CloseOrphanedParticipant;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {};
+
+        let participant: Participant = account_loader.load(&self.participant).await.unwrap();
+
+        let accounts = Self::Accounts {
+            distribution: participant.distribution,
+            participant: self.participant,
+            voter_authority: self.voter_authority.pubkey(),
+            payer: participant.payer,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.voter_authority]
+    }
+}
+
+pub struct ClaimAndLockInstruction<'keypair> {
+    pub participant: Pubkey,
+    pub voter_authority: &'keypair Keypair,
+    pub deposit_entry_index: u8,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for ClaimAndLockInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::ClaimAndLock;
+    type Instruction = distribute_by_locked_vote_weight::instruction::ClaimAndLock;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {
+            deposit_entry_index: self.deposit_entry_index,
+        };
+
+        let participant: Participant = account_loader.load(&self.participant).await.unwrap();
+        let distribution: Distribution = account_loader
+            .load(&participant.distribution)
+            .await
+            .unwrap();
+        let voter_vault = spl_associated_token_account::get_associated_token_address(
+            &participant.voter,
+            &distribution.mint,
+        );
+
+        let accounts = Self::Accounts {
+            distribution: participant.distribution,
+            participant: self.participant,
+            vault: distribution.vault,
+            mint: distribution.mint,
+            registrar: distribution.registrar,
+            voter: participant.voter,
+            voter_vault,
+            voter_authority: self.voter_authority.pubkey(),
+            payer: participant.payer,
+            vsr_program: voter_stake_registry::id(),
+            system_program: System::id(),
+            token_program: Token::id(),
+            associated_token_program: spl_associated_token_account::id(),
+            rent: sysvar::rent::Rent::id(),
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        instruction.accounts.extend(reward_vault_metas(
+            &distribution,
+            &self.voter_authority.pubkey(),
+        ));
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.voter_authority]
+    }
+}
+
+pub struct CancelDistributionInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub authority: &'keypair Keypair,
+    pub target_token: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for CancelDistributionInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::CancelDistribution;
+    type Instruction = distribute_by_locked_vote_weight::instruction::CancelDistribution;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {};
+
+        let distribution: Distribution = account_loader.load(&self.distribution).await.unwrap();
+
+        let accounts = Self::Accounts {
+            distribution: self.distribution,
+            authority: self.authority.pubkey(),
+            vault: distribution.vault,
+            target_token: self.target_token,
+            token_program: Token::id(),
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        instruction
+            .accounts
+            .extend(reward_vault_metas(&distribution, &distribution.admin));
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.authority]
+    }
+}
+
+pub struct CloseParticipantInstruction<'keypair> {
+    pub participant: Pubkey,
+    pub voter_authority: &'keypair Keypair,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for CloseParticipantInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::CloseParticipant;
+    type Instruction = distribute_by_locked_vote_weight::instruction::CloseParticipant;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {};
+
+        let participant: Participant = account_loader.load(&self.participant).await.unwrap();
+
+        let accounts = Self::Accounts {
+            distribution: participant.distribution,
+            participant: self.participant,
+            voter_authority: self.voter_authority.pubkey(),
+            payer: participant.payer,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.voter_authority]
+    }
+}
+
+pub struct LogInfoInstruction {
+    pub distribution: Pubkey,
+    pub voter: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for LogInfoInstruction {
+    type Accounts = distribute_by_locked_vote_weight::accounts::LogInfo;
+    type Instruction = distribute_by_locked_vote_weight::instruction::LogInfo;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {};
+
+        let participant = Pubkey::find_program_address(
+            &[
+                self.distribution.as_ref(),
+                b"participant".as_ref(),
+                self.voter.as_ref(),
+            ],
+            &program_id,
+        )
+        .0;
+        let distribution: Distribution = account_loader.load(&self.distribution).await.unwrap();
+
+        let accounts = Self::Accounts {
+            distribution: self.distribution,
+            vault: distribution.vault,
+            participant,
+            voter: self.voter,
+            registrar: distribution.registrar,
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        instruction.accounts.extend(
+            distribution
+                .used_reward_vaults()
+                .iter()
+                .map(|reward_vault| AccountMeta::new_readonly(reward_vault.vault, false)),
+        );
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![]
+    }
+}
+
+pub struct MigrateDistributionInstruction<'keypair> {
+    pub distribution: Pubkey,
+    pub vault: Pubkey,
+    pub payer: &'keypair Keypair,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for MigrateDistributionInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::MigrateDistribution;
+    type Instruction = distribute_by_locked_vote_weight::instruction::MigrateDistribution;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {};
+
+        let accounts = Self::Accounts {
+            distribution: self.distribution,
+            vault: self.vault,
+            payer: self.payer.pubkey(),
+            system_program: System::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.payer]
+    }
+}
+
+pub struct MigrateParticipantInstruction<'keypair> {
+    pub participant: Pubkey,
+    pub payer: &'keypair Keypair,
+}
+#[async_trait::async_trait(?Send)]
+impl<'keypair> ClientInstruction for MigrateParticipantInstruction<'keypair> {
+    type Accounts = distribute_by_locked_vote_weight::accounts::MigrateParticipant;
+    type Instruction = distribute_by_locked_vote_weight::instruction::MigrateParticipant;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = distribute_by_locked_vote_weight::id();
+        let instruction = Self::Instruction {};
+
+        let accounts = Self::Accounts {
+            participant: self.participant,
+            payer: self.payer.pubkey(),
+            system_program: System::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<&Keypair> {
+        vec![self.payer]
     }
 }
@@ -110,6 +110,16 @@ impl TestConfig {
         account.data = bytes;
         self.accounts.push((pubkey, account));
     }
+
+    /// Adds an account with raw `data` that is just rent exempt, for example
+    /// one with the layout of an earlier program version.
+    #[allow(dead_code)]
+    pub fn add_raw_account(&mut self, pubkey: Pubkey, data: Vec<u8>, owner: Pubkey) {
+        let lamports = solana_sdk::rent::Rent::default().minimum_balance(data.len());
+        let mut account = solana_sdk::account::Account::new(lamports, data.len(), &owner);
+        account.data = data;
+        self.accounts.push((pubkey, account));
+    }
 }
 
 impl TestContext {
@@ -6,7 +6,7 @@ use anchor_spl::token::TokenAccount;
 use solana_program::{program_pack::Pack, rent::*, system_instruction};
 use solana_program_test::*;
 use solana_sdk::{
-    account::ReadableAccount,
+    account::{AccountSharedData, ReadableAccount, WritableAccount},
     instruction::Instruction,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
@@ -100,6 +100,20 @@ impl SolanaCookie {
         return keypair.pubkey();
     }
 
+    #[allow(dead_code)]
+    pub async fn create_associated_token_account(&self, owner: &Pubkey, mint: Pubkey) -> Pubkey {
+        let instructions = [
+            spl_associated_token_account::create_associated_token_account(
+                &self.context.borrow().payer.pubkey(),
+                owner,
+                &mint,
+            ),
+        ];
+
+        self.process_transaction(&instructions, None).await.unwrap();
+        spl_associated_token_account::get_associated_token_address(owner, &mint)
+    }
+
     #[allow(dead_code)]
     pub async fn transfer_token(
         &self,
@@ -137,6 +151,40 @@ impl SolanaCookie {
         )
     }
 
+    /// Overwrites the data of an anchor account, to simulate changes made by
+    /// other programs.
+    #[allow(dead_code)]
+    pub async fn set_anchor_account_data<T: bytemuck::Pod + anchor_lang::Discriminator>(
+        &self,
+        address: Pubkey,
+        data: &T,
+    ) {
+        let mut context = self.context.borrow_mut();
+        let mut account: AccountSharedData = context
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .unwrap()
+            .into();
+        let mut bytes = T::discriminator().to_vec();
+        bytes.extend_from_slice(bytemuck::bytes_of(data));
+        account.set_data(bytes);
+        context.set_account(&address, &account);
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_account_lamports(&self, address: Pubkey) -> u64 {
+        self.context
+            .borrow_mut()
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .map(|a| a.lamports)
+            .unwrap_or(0)
+    }
+
     #[allow(dead_code)]
     pub async fn get_account_opt<T: AccountDeserialize>(&self, address: Pubkey) -> Option<T> {
         let data = self.get_account_data(address).await?;
@@ -3,6 +3,9 @@ use solana_program::program_error::ProgramError;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
 
+use super::{send_tx, LogInfoInstruction, SolanaCookie};
+use distribute_by_locked_vote_weight::events;
+
 #[allow(dead_code)]
 pub fn gen_signer_seeds<'a>(nonce: &'a u64, acc_pk: &'a Pubkey) -> [&'a [u8]; 2] {
     [acc_pk.as_ref(), bytes_of(nonce)]
@@ -32,3 +35,40 @@ pub fn create_signer_key_and_nonce(program_id: &Pubkey, acc_pk: &Pubkey) -> (Pub
 pub fn clone_keypair(keypair: &Keypair) -> Keypair {
     Keypair::from_base58_string(&keypair.to_base58_string())
 }
+
+/// The share of `amount` for `weight` out of `total_weight`, rounded like the
+/// distribution's reward accounting does.
+#[allow(dead_code)]
+pub fn expected_share(amount: u64, weight: u64, total_weight: u128) -> u64 {
+    use distribute_by_locked_vote_weight::state::REWARD_PER_WEIGHT_SCALE;
+    let reward_per_weight = amount as u128 * REWARD_PER_WEIGHT_SCALE / total_weight;
+    (weight as u128 * reward_per_weight / REWARD_PER_WEIGHT_SCALE) as u64
+}
+
+/// Decodes an anchor event from a base64 program log line, None if the line
+/// isn't an event of type `T`.
+#[allow(dead_code)]
+pub fn deserialize_event<T: anchor_lang::Event>(event: &str) -> Option<T> {
+    let data = base64::decode(event).ok()?;
+    if data.len() < 8 || data[0..8] != T::discriminator() {
+        return None;
+    }
+    T::try_from_slice(&data[8..]).ok()
+}
+
+/// Runs LogInfo for the voter and returns the Info event it emitted.
+#[allow(dead_code)]
+pub async fn get_info(solana: &SolanaCookie, distribution: Pubkey, voter: Pubkey) -> events::Info {
+    solana.advance_by_slots(1).await;
+    send_tx(
+        solana,
+        LogInfoInstruction {
+            distribution,
+            voter,
+        },
+    )
+    .await
+    .unwrap();
+    let log = solana.program_log();
+    deserialize_event::<events::Info>(&log[1]).unwrap()
+}
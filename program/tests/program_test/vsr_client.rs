@@ -4,7 +4,7 @@ use solana_sdk::instruction;
 use solana_sdk::signature::{Keypair, Signer};
 
 use super::client::*;
-use super::{TestConfig, MANGO_MINT_PK};
+use super::{SolanaCookie, TestConfig, MANGO_MINT_PK};
 use voter_stake_registry::state::*;
 
 #[allow(dead_code)]
@@ -17,6 +17,7 @@ pub struct VoterCookie {
 #[allow(dead_code)]
 pub struct VoterStakeRegistryCookie {
     pub registrar: Pubkey,
+    pub voting_mints: Vec<Pubkey>,
     pub voters: Vec<VoterCookie>,
 }
 
@@ -26,10 +27,11 @@ pub fn setup_mock_registrar_and_voters(
     now_ts: u64,
 ) -> VoterStakeRegistryCookie {
     let registrar = Pubkey::new_unique();
+    let voting_mints = vec![*MANGO_MINT_PK, Pubkey::new_unique()];
     {
         let mut registrar_data = Registrar::default();
         registrar_data.voting_mints[0] = VotingMintConfig {
-            mint: *MANGO_MINT_PK,
+            mint: voting_mints[0],
             grant_authority: Pubkey::default(),
             baseline_vote_weight_scaled_factor: 1_000_000_000,
             max_extra_lockup_vote_weight_scaled_factor: 1_000_000_000,
@@ -38,6 +40,11 @@ pub fn setup_mock_registrar_and_voters(
             reserved1: [0; 7],
             reserved2: [0; 7],
         };
+        // a second voting mint that no voter has deposits in
+        registrar_data.voting_mints[1] = VotingMintConfig {
+            mint: voting_mints[1],
+            ..registrar_data.voting_mints[0]
+        };
         test_config.add_anchor_account(registrar, registrar_data, voter_stake_registry::id());
     }
 
@@ -96,7 +103,22 @@ pub fn setup_mock_registrar_and_voters(
         });
     }
 
-    VoterStakeRegistryCookie { registrar, voters }
+    VoterStakeRegistryCookie {
+        registrar,
+        voting_mints,
+        voters,
+    }
+}
+
+/// Simulates a clawback, leaving `locked_amount` in the voter's deposit.
+#[allow(dead_code)]
+pub async fn claw_back(solana: &SolanaCookie, voter: &VoterCookie, locked_amount: u64) {
+    let mut voter_data: Voter = solana.get_account(voter.pubkey).await;
+    voter_data.deposits[0].amount_deposited_native = locked_amount;
+    voter_data.deposits[0].amount_initially_locked_native = locked_amount;
+    solana
+        .set_anchor_account_data(voter.pubkey, &voter_data)
+        .await;
 }
 
 pub struct DepositInstruction<'keypair> {
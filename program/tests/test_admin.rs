@@ -0,0 +1,256 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transport::TransportError,
+};
+
+use distribute_by_locked_vote_weight::state::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_admin() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let new_admin = &Keypair::new();
+    let ops = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let payer_mint0_account = context.users[0].token_accounts[0];
+    let other_mint0_account = context.users[1].token_accounts[0];
+    let distribution_amount = 1000;
+    let funded_amount = 100;
+
+    //
+    // SETUP: distribution
+    //
+    let accounts = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: 0,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: 0,
+            keep_participants: false,
+            min_weight: 0,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap();
+    let distribution = accounts.distribution;
+    let vault = accounts.vault;
+
+    solana
+        .transfer_token(payer_mint0_account, payer, vault, distribution_amount)
+        .await;
+
+    //
+    // TEST: Only the admin can propose a new admin
+    //
+    assert!(send_tx(
+        solana,
+        SetAdminInstruction {
+            distribution,
+            admin: new_admin,
+            new_admin: new_admin.pubkey(),
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        SetAdminInstruction {
+            distribution,
+            admin,
+            new_admin: new_admin.pubkey(),
+        },
+    )
+    .await
+    .unwrap();
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(distribution_data.admin, admin.pubkey());
+    assert_eq!(distribution_data.pending_admin, new_admin.pubkey());
+
+    //
+    // TEST: Only the proposed admin can accept
+    //
+    assert!(send_tx(
+        solana,
+        AcceptAdminInstruction {
+            distribution,
+            pending_admin: ops,
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        AcceptAdminInstruction {
+            distribution,
+            pending_admin: new_admin,
+        },
+    )
+    .await
+    .unwrap();
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(distribution_data.admin, new_admin.pubkey());
+    assert_eq!(distribution_data.creator, admin.pubkey());
+    assert_eq!(distribution_data.pending_admin, Pubkey::default());
+
+    //
+    // TEST: The old admin lost its permissions, the new one can assign roles
+    //
+    assert!(send_tx(
+        solana,
+        SetRolesInstruction {
+            distribution,
+            admin,
+            funder: ops.pubkey(),
+            canceller: ops.pubkey(),
+        },
+    )
+    .await
+    .is_err());
+
+    // without the funder role, ops can't restrict funding
+    assert!(send_tx(
+        solana,
+        SetFundingRestrictionInstruction {
+            distribution,
+            authority: ops,
+            registration_only: true,
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        SetRolesInstruction {
+            distribution,
+            admin: new_admin,
+            funder: ops.pubkey(),
+            canceller: ops.pubkey(),
+        },
+    )
+    .await
+    .unwrap();
+
+    //
+    // TEST: The funder can restrict funding
+    //
+    send_tx(
+        solana,
+        SetFundingRestrictionInstruction {
+            distribution,
+            authority: ops,
+            registration_only: true,
+        },
+    )
+    .await
+    .unwrap();
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert!(distribution_data.funding_registration_only());
+
+    //
+    // TEST: Once a funder is set, only the funder and the admin can fund
+    //
+    assert!(send_tx(
+        solana,
+        FundDistributionInstruction {
+            distribution,
+            funder: payer,
+            funder_token: payer_mint0_account,
+            amount: funded_amount,
+        },
+    )
+    .await
+    .is_err());
+
+    let ops_mint0_account = solana
+        .create_token_account(&ops.pubkey(), mint0.pubkey)
+        .await;
+    solana
+        .transfer_token(payer_mint0_account, payer, ops_mint0_account, funded_amount)
+        .await;
+    send_tx(
+        solana,
+        FundDistributionInstruction {
+            distribution,
+            funder: ops,
+            funder_token: ops_mint0_account,
+            amount: funded_amount,
+        },
+    )
+    .await
+    .unwrap();
+
+    //
+    // TEST: The canceller can only refund to the admin
+    //
+    assert!(send_tx(
+        solana,
+        CancelDistributionInstruction {
+            distribution,
+            authority: ops,
+            target_token: other_mint0_account,
+        },
+    )
+    .await
+    .is_err());
+
+    let new_admin_mint0_account = solana
+        .create_token_account(&new_admin.pubkey(), mint0.pubkey)
+        .await;
+    send_tx(
+        solana,
+        CancelDistributionInstruction {
+            distribution,
+            authority: ops,
+            target_token: new_admin_mint0_account,
+        },
+    )
+    .await
+    .unwrap();
+
+    // the distribution still signs with the seeds from creation
+    assert_eq!(
+        solana.token_account_balance(new_admin_mint0_account).await,
+        distribution_amount + funded_amount
+    );
+    assert_eq!(solana.token_account_balance(vault).await, 0);
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert!(distribution_data.is_cancelled());
+
+    Ok(())
+}
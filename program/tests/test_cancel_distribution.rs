@@ -0,0 +1,166 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transport::TransportError,
+};
+
+use distribute_by_locked_vote_weight::state::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_cancel_distribution() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let payer_mint0_account = context.users[0].token_accounts[0];
+    let admin_mint0_account = context.users[1].token_accounts[0];
+    let admin_start_balance = solana.token_account_balance(admin_mint0_account).await;
+    let distribution_amount = 1000;
+    let voter0 = &vsr.voters[0];
+    let voter1 = &vsr.voters[1];
+
+    //
+    // SETUP: distribution and participant
+    //
+    let accounts = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: 0,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: 0,
+            keep_participants: false,
+            min_weight: 0,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap();
+    let distribution = accounts.distribution;
+    let vault = accounts.vault;
+
+    solana
+        .transfer_token(payer_mint0_account, payer, vault, distribution_amount)
+        .await;
+
+    let participant0 = send_tx(
+        solana,
+        CreateParticipantInstruction {
+            distribution,
+            voter: voter0.pubkey,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .participant;
+
+    //
+    // TEST: Cancel, refunding the vault
+    //
+    assert!(send_tx(
+        solana,
+        CancelDistributionInstruction {
+            distribution,
+            authority: &Keypair::new(),
+            target_token: admin_mint0_account,
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        CancelDistributionInstruction {
+            distribution,
+            authority: admin,
+            target_token: admin_mint0_account,
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(solana.token_account_balance(vault).await, 0);
+    assert_eq!(
+        solana.token_account_balance(admin_mint0_account).await,
+        admin_start_balance + distribution_amount
+    );
+
+    //
+    // TEST: Registering and updating is impossible now
+    //
+    assert!(send_tx(
+        solana,
+        CreateParticipantInstruction {
+            distribution,
+            voter: voter1.pubkey,
+            payer,
+        },
+    )
+    .await
+    .is_err());
+    assert!(send_tx(
+        solana,
+        UpdateParticipantInstruction {
+            participant: participant0
+        }
+    )
+    .await
+    .is_err());
+
+    //
+    // TEST: The distribution can only be closed after the participant is
+    //
+    let close_distribution = || CloseDistributionInstruction {
+        distribution,
+        admin,
+        target_token: admin_mint0_account,
+        rent_receiver: payer.pubkey(),
+    };
+    assert!(send_tx(solana, close_distribution()).await.is_err());
+
+    send_tx(
+        solana,
+        CloseParticipantInstruction {
+            participant: participant0,
+            voter_authority: &voter0.authority,
+        },
+    )
+    .await
+    .unwrap();
+    assert!(solana.get_account_data(participant0).await.is_none());
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(distribution_data.participant_count, 0);
+    assert_eq!(distribution_data.participant_total_weight, 0);
+
+    solana.advance_by_slots(1).await;
+    send_tx(solana, close_distribution()).await.unwrap();
+    assert!(solana.get_account_data(distribution).await.is_none());
+
+    Ok(())
+}
@@ -0,0 +1,287 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transport::TransportError,
+};
+use voter_stake_registry::state::{LockupKind, Voter};
+
+use distribute_by_locked_vote_weight::state::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_claim_and_lock() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let payer_mint0_account = context.users[0].token_accounts[0];
+    let distribution_amount = 1000;
+    let voter0 = &vsr.voters[0];
+    let voter1 = &vsr.voters[1];
+
+    //
+    // SETUP: distribution that requires locking claimed tokens
+    //
+    let accounts = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: 0,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: 0,
+            keep_participants: false,
+            min_weight: 0,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap();
+    let distribution = accounts.distribution;
+    let vault = accounts.vault;
+
+    send_tx(
+        solana,
+        SetClaimLockupInstruction {
+            distribution,
+            admin,
+            lockup_kind: LockupKind::Cliff,
+            lockup_periods: 365,
+            lockup_required: true,
+        },
+    )
+    .await
+    .unwrap();
+
+    solana
+        .transfer_token(payer_mint0_account, payer, vault, distribution_amount)
+        .await;
+
+    let mut participants = vec![];
+    for voter in [voter0, voter1] {
+        let participant = send_tx(
+            solana,
+            CreateParticipantInstruction {
+                distribution,
+                voter: voter.pubkey,
+                payer,
+            },
+        )
+        .await
+        .unwrap()
+        .participant;
+        participants.push(participant);
+    }
+    let participant0_data: Participant = solana.get_account(participants[0]).await;
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    let share0 = expected_share(
+        distribution_amount,
+        participant0_data.weight,
+        distribution_data.participant_total_weight,
+    );
+
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin,
+            time_offset: 1000,
+        },
+    )
+    .await
+    .unwrap();
+
+    //
+    // TEST: Plain claims are impossible
+    //
+    assert!(send_tx(
+        solana,
+        ClaimInstruction {
+            participant: participants[1],
+            voter_authority: &voter1.authority,
+            target_token: payer_mint0_account,
+            payer: payer.pubkey(),
+        },
+    )
+    .await
+    .is_err());
+
+    //
+    // TEST: Claim into a new cliff lockup
+    //
+    let voter0_vault =
+        spl_associated_token_account::get_associated_token_address(&voter0.pubkey, &mint0.pubkey);
+    let voter0_vault_balance = solana.token_account_balance(voter0_vault).await;
+
+    send_tx(
+        solana,
+        ClaimAndLockInstruction {
+            participant: participants[0],
+            voter_authority: &voter0.authority,
+            deposit_entry_index: 1,
+        },
+    )
+    .await
+    .unwrap();
+
+    assert!(solana.get_account_data(participants[0]).await.is_none());
+    assert_eq!(
+        solana.token_account_balance(voter0_vault).await,
+        voter0_vault_balance + share0
+    );
+
+    let voter_data: Voter = solana.get_account(voter0.pubkey).await;
+    let deposit = voter_data.deposits[1];
+    assert!(deposit.is_used);
+    assert_eq!(deposit.amount_deposited_native, share0);
+    assert_eq!(deposit.lockup.kind, LockupKind::Cliff);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_claim_and_lock_reuses_deposit_entry() -> Result<(), TransportError> {
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let payer_mint0_account = context.users[0].token_accounts[0];
+    let voter0 = &vsr.voters[0];
+
+    //
+    // SETUP: distribution that keeps participants, so they can claim top-ups
+    //
+    let accounts = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: 0,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: 0,
+            keep_participants: true,
+            min_weight: 0,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap();
+    let distribution = accounts.distribution;
+    let vault = accounts.vault;
+
+    send_tx(
+        solana,
+        SetClaimLockupInstruction {
+            distribution,
+            admin,
+            lockup_kind: LockupKind::Cliff,
+            lockup_periods: 365,
+            lockup_required: true,
+        },
+    )
+    .await
+    .unwrap();
+
+    solana
+        .transfer_token(payer_mint0_account, payer, vault, 1000)
+        .await;
+
+    let participant0 = send_tx(
+        solana,
+        CreateParticipantInstruction {
+            distribution,
+            voter: voter0.pubkey,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .participant;
+
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin,
+            time_offset: 1000,
+        },
+    )
+    .await
+    .unwrap();
+
+    let voter0_vault =
+        spl_associated_token_account::get_associated_token_address(&voter0.pubkey, &mint0.pubkey);
+    let voter0_vault_balance = solana.token_account_balance(voter0_vault).await;
+
+    send_tx(
+        solana,
+        ClaimAndLockInstruction {
+            participant: participant0,
+            voter_authority: &voter0.authority,
+            deposit_entry_index: 1,
+        },
+    )
+    .await
+    .unwrap();
+    let participant0_data: Participant = solana.get_account(participant0).await;
+    assert_eq!(participant0_data.lockup_deposit_entry(), Some(1));
+
+    //
+    // TEST: Claiming a top-up deposits into the same entry
+    //
+    solana
+        .transfer_token(payer_mint0_account, payer, vault, 500)
+        .await;
+    send_tx(
+        solana,
+        ClaimAndLockInstruction {
+            participant: participant0,
+            voter_authority: &voter0.authority,
+            deposit_entry_index: 2,
+        },
+    )
+    .await
+    .unwrap();
+
+    let claimed = solana.token_account_balance(voter0_vault).await - voter0_vault_balance;
+    assert!(claimed > 1000);
+    let voter_data: Voter = solana.get_account(voter0.pubkey).await;
+    assert_eq!(voter_data.deposits[1].amount_deposited_native, claimed);
+    assert!(!voter_data.deposits[2].is_used);
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transport::TransportError,
+};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_claim_for() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let cranker = &context.users[1].key;
+    let mint0 = &context.mints[0];
+    let payer_mint0_account = context.users[0].token_accounts[0];
+    let distribution_amount = 1000;
+    let voter0 = &vsr.voters[0];
+    let voter1 = &vsr.voters[1];
+
+    //
+    // SETUP: distribution and participants
+    //
+    let accounts = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: 0,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: 0,
+            keep_participants: false,
+            min_weight: 0,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap();
+    let distribution = accounts.distribution;
+    let vault = accounts.vault;
+
+    solana
+        .transfer_token(payer_mint0_account, payer, vault, distribution_amount)
+        .await;
+
+    let mut participants = vec![];
+    for voter in [voter0, voter1] {
+        let participant = send_tx(
+            solana,
+            CreateParticipantInstruction {
+                distribution,
+                voter: voter.pubkey,
+                payer,
+            },
+        )
+        .await
+        .unwrap()
+        .participant;
+        participants.push(participant);
+    }
+
+    // cranking isn't possible during registration
+    assert!(send_tx(
+        solana,
+        ClaimForInstruction {
+            participant: participants[0],
+            cranker,
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin,
+            time_offset: 1000,
+        },
+    )
+    .await
+    .unwrap();
+
+    //
+    // TEST: Claim for both participants into their voter authority's ATA
+    //
+    solana.advance_by_slots(1).await;
+    let mut total_claimed = 0;
+    for (participant, voter) in participants.iter().zip([voter0, voter1]) {
+        send_tx(
+            solana,
+            ClaimForInstruction {
+                participant: *participant,
+                cranker,
+            },
+        )
+        .await
+        .unwrap();
+        assert!(solana.get_account_data(*participant).await.is_none());
+
+        let target_token = spl_associated_token_account::get_associated_token_address(
+            &voter.authority.pubkey(),
+            &mint0.pubkey,
+        );
+        let balance = solana.token_account_balance(target_token).await;
+        assert!(balance > 0);
+        total_claimed += balance;
+    }
+
+    assert_eq!(
+        solana.token_account_balance(vault).await,
+        distribution_amount - total_claimed
+    );
+
+    Ok(())
+}
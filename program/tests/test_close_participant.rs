@@ -0,0 +1,149 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transport::TransportError,
+};
+
+use distribute_by_locked_vote_weight::state::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_close_participant() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let voter0 = &vsr.voters[0];
+    let voter1 = &vsr.voters[1];
+
+    //
+    // SETUP: distribution and participants
+    //
+    let distribution = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: 0,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: 0,
+            keep_participants: false,
+            min_weight: 0,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .distribution;
+
+    let participant0 = send_tx(
+        solana,
+        CreateParticipantInstruction {
+            distribution,
+            voter: voter0.pubkey,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .participant;
+    let participant1 = send_tx(
+        solana,
+        CreateParticipantInstruction {
+            distribution,
+            voter: voter1.pubkey,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .participant;
+    let participant1_data: Participant = solana.get_account(participant1).await;
+
+    //
+    // TEST: Participant 0 withdraws during registration
+    //
+
+    // only the voter authority can close
+    assert!(send_tx(
+        solana,
+        CloseParticipantInstruction {
+            participant: participant0,
+            voter_authority: &voter1.authority,
+        },
+    )
+    .await
+    .is_err());
+
+    let payer_lamports = solana.get_account_lamports(payer.pubkey()).await;
+    let participant_lamports = solana.get_account_lamports(participant0).await;
+    send_tx(
+        solana,
+        CloseParticipantInstruction {
+            participant: participant0,
+            voter_authority: &voter0.authority,
+        },
+    )
+    .await
+    .unwrap();
+    assert!(solana.get_account_data(participant0).await.is_none());
+    assert_eq!(
+        solana.get_account_lamports(payer.pubkey()).await,
+        payer_lamports + participant_lamports
+    );
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(distribution_data.participant_count, 1);
+    assert_eq!(
+        distribution_data.participant_total_weight,
+        participant1_data.weight as u128
+    );
+
+    //
+    // TEST: After registration participants can't withdraw anymore
+    //
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin,
+            time_offset: 1000,
+        },
+    )
+    .await
+    .unwrap();
+
+    assert!(send_tx(
+        solana,
+        CloseParticipantInstruction {
+            participant: participant1,
+            voter_authority: &voter1.authority,
+        },
+    )
+    .await
+    .is_err());
+
+    Ok(())
+}
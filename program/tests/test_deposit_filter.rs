@@ -0,0 +1,155 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, transport::TransportError};
+
+use distribute_by_locked_vote_weight::state::*;
+use voter_stake_registry::state::LockupKind;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_deposit_filter() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let voter0 = &vsr.voters[0];
+    // -1 is due to rounding down as end_ts > now_ts
+    let weight0 = voter0.locked_amount * 12 / 60 - 1;
+    let day = 24 * 60 * 60;
+
+    // the mock voters have a single constant lockup of 365 days
+    let filters = [
+        (1 << LockupKind::Cliff as u8, 0, None),
+        (1 << LockupKind::Constant as u8, 0, Some(weight0)),
+        (
+            1 << LockupKind::Cliff as u8 | 1 << LockupKind::Constant as u8,
+            0,
+            Some(weight0),
+        ),
+        (0, now_ts + 400 * day, None),
+        (0, now_ts + 300 * day, Some(weight0)),
+    ];
+
+    for (index, (lockup_kinds, min_lockup_end_ts, expected_weight)) in
+        filters.into_iter().enumerate()
+    {
+        let distribution = send_tx(
+            solana,
+            CreateDistributionInstruction {
+                index: index as u64,
+                end_ts: now_ts + 100,
+                weight_ts: now_ts + 100,
+                claim_end_ts: 0,
+                keep_participants: false,
+                min_weight: 0,
+                registrar: vsr.registrar,
+                mint: mint0.pubkey,
+                admin,
+                payer,
+            },
+        )
+        .await
+        .unwrap()
+        .distribution;
+
+        send_tx(
+            solana,
+            SetDepositFilterInstruction {
+                distribution,
+                admin,
+                lockup_kinds,
+                min_lockup_end_ts,
+            },
+        )
+        .await
+        .unwrap();
+
+        //
+        // TEST: Only eligible deposits count towards the weight
+        //
+        let result = send_tx(
+            solana,
+            CreateParticipantInstruction {
+                distribution,
+                voter: voter0.pubkey,
+                payer,
+            },
+        )
+        .await;
+        match expected_weight {
+            Some(weight) => {
+                let participant_data: Participant =
+                    solana.get_account(result.unwrap().participant).await;
+                assert_eq!(participant_data.weight, weight);
+
+                // the filter can't change once participants registered
+                assert!(send_tx(
+                    solana,
+                    SetDepositFilterInstruction {
+                        distribution,
+                        admin,
+                        lockup_kinds: 0,
+                        min_lockup_end_ts: 0,
+                    },
+                )
+                .await
+                .is_err());
+            }
+            None => assert!(result.is_err()),
+        }
+    }
+
+    //
+    // TEST: Unknown lockup kinds are rejected
+    //
+    let distribution = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: filters.len() as u64,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: 0,
+            keep_participants: false,
+            min_weight: 0,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .distribution;
+    assert!(send_tx(
+        solana,
+        SetDepositFilterInstruction {
+            distribution,
+            admin,
+            lockup_kinds: 1 << 5,
+            min_lockup_end_ts: 0,
+        },
+    )
+    .await
+    .is_err());
+
+    Ok(())
+}
@@ -0,0 +1,282 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transport::TransportError,
+};
+
+use distribute_by_locked_vote_weight::state::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_epochs() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let payer_mint0_account = context.users[0].token_accounts[0];
+    let epoch0_amount = 1000;
+    let epoch1_amount = 600;
+    let voter0 = &vsr.voters[0];
+    let voter1 = &vsr.voters[1];
+
+    //
+    // SETUP: epoch distribution with one participant
+    //
+    let accounts = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: 0,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: 0,
+            keep_participants: false,
+            min_weight: 0,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap();
+    let distribution = accounts.distribution;
+    let vault = accounts.vault;
+
+    send_tx(
+        solana,
+        SetEpochsInstruction {
+            distribution,
+            admin,
+            epoch_duration: 1000,
+        },
+    )
+    .await
+    .unwrap();
+
+    solana
+        .transfer_token(payer_mint0_account, payer, vault, epoch0_amount)
+        .await;
+
+    let participant0 = send_tx(
+        solana,
+        CreateParticipantInstruction {
+            distribution,
+            voter: voter0.pubkey,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .participant;
+    let mut targets = vec![];
+    for voter in [voter0, voter1] {
+        targets.push(
+            solana
+                .create_token_account(&voter.authority.pubkey(), mint0.pubkey)
+                .await,
+        );
+    }
+
+    //
+    // TEST: The only participant gets all of epoch 0
+    //
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin,
+            time_offset: 1000,
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        ClaimInstruction {
+            participant: participant0,
+            voter_authority: &voter0.authority,
+            target_token: targets[0],
+            payer: payer.pubkey(),
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        solana.token_account_balance(targets[0]).await,
+        epoch0_amount
+    );
+
+    // the participant is kept, but can only claim once per epoch
+    solana.advance_by_slots(1).await;
+    assert!(send_tx(
+        solana,
+        ClaimInstruction {
+            participant: participant0,
+            voter_authority: &voter0.authority,
+            target_token: targets[0],
+            payer: payer.pubkey(),
+        },
+    )
+    .await
+    .is_err());
+
+    //
+    // TEST: Registering during an epoch counts from the next one
+    //
+    let participant1 = send_tx(
+        solana,
+        CreateParticipantInstruction {
+            distribution,
+            voter: voter1.pubkey,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .participant;
+    let participant1_data: Participant = solana.get_account(participant1).await;
+    assert_eq!(participant1_data.epoch_weight(0), 0);
+    assert!(participant1_data.epoch_weight(1) > 0);
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    let weight0 = solana.get_account::<Participant>(participant0).await.weight;
+    assert_eq!(
+        distribution_data.epoch_participant_total_weight(0),
+        weight0 as u128
+    );
+
+    //
+    // TEST: Epoch 1 distributes the new funds to both participants
+    //
+    solana
+        .transfer_token(payer_mint0_account, payer, vault, epoch1_amount)
+        .await;
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin,
+            time_offset: 2000,
+        },
+    )
+    .await
+    .unwrap();
+
+    for (i, participant) in [participant0, participant1].into_iter().enumerate() {
+        send_tx(
+            solana,
+            ClaimInstruction {
+                participant,
+                voter_authority: &vsr.voters[i].authority,
+                target_token: targets[i],
+                payer: payer.pubkey(),
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    let total_weight = distribution_data.epoch_participant_total_weight(1);
+    let weight1 = participant1_data.weight;
+    assert_eq!(total_weight, (weight0 + weight1) as u128);
+    let share0 = (epoch1_amount as u128 * weight0 as u128 / total_weight) as u64;
+    let share1 = (epoch1_amount as u128 * weight1 as u128 / total_weight) as u64;
+    assert_eq!(
+        solana.token_account_balance(targets[0]).await,
+        epoch0_amount + share0
+    );
+    assert_eq!(solana.token_account_balance(targets[1]).await, share1);
+    // rounding dust carries over
+    assert_eq!(
+        solana.token_account_balance(vault).await,
+        epoch1_amount - share0 - share1
+    );
+
+    //
+    // TEST: Refreshing the weight only affects the next epoch
+    //
+    send_tx(
+        solana,
+        UpdateParticipantInstruction {
+            participant: participant0,
+        },
+    )
+    .await
+    .unwrap();
+    let participant0_data: Participant = solana.get_account(participant0).await;
+    assert_eq!(participant0_data.epoch_weight(1), weight0);
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(
+        distribution_data.epoch_participant_total_weight(1),
+        total_weight
+    );
+    assert_eq!(
+        distribution_data.epoch_participant_total_weight(2),
+        (participant0_data.weight + weight1) as u128
+    );
+
+    //
+    // TEST: Participants can leave at any time
+    //
+    send_tx(
+        solana,
+        CloseParticipantInstruction {
+            participant: participant1,
+            voter_authority: &voter1.authority,
+        },
+    )
+    .await
+    .unwrap();
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(distribution_data.participant_count, 1);
+    assert_eq!(
+        distribution_data.epoch_participant_total_weight(2),
+        participant0_data.weight as u128
+    );
+
+    //
+    // TEST: The distribution can only be closed once all participants left
+    //
+    let close_distribution = || CloseDistributionInstruction {
+        distribution,
+        admin,
+        target_token: payer_mint0_account,
+        rent_receiver: payer.pubkey(),
+    };
+    assert!(send_tx(solana, close_distribution()).await.is_err());
+    send_tx(
+        solana,
+        CloseParticipantInstruction {
+            participant: participant0,
+            voter_authority: &voter0.authority,
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(solana, close_distribution()).await.unwrap();
+    assert!(solana.get_account_data(distribution).await.is_none());
+
+    Ok(())
+}
@@ -3,39 +3,14 @@
 use program_test::*;
 use solana_program_test::*;
 use solana_sdk::{
-    pubkey::Pubkey,
     signature::{Keypair, Signer},
     transport::TransportError,
 };
 
-use distribute_by_locked_vote_weight::events;
 use distribute_by_locked_vote_weight::state::*;
 
 mod program_test;
 
-fn deserialize_event<T: anchor_lang::Event>(event: &str) -> Option<T> {
-    let data = base64::decode(event).ok()?;
-    if data.len() < 8 || data[0..8] != T::discriminator() {
-        return None;
-    }
-    T::try_from_slice(&data[8..]).ok()
-}
-
-async fn get_info(solana: &SolanaCookie, distribution: Pubkey, voter: Pubkey) -> events::Info {
-    solana.advance_by_slots(1).await;
-    send_tx(
-        solana,
-        LogInfoInstruction {
-            distribution,
-            voter,
-        },
-    )
-    .await
-    .unwrap();
-    let log = solana.program_log();
-    deserialize_event::<distribute_by_locked_vote_weight::events::Info>(&log[1]).unwrap()
-}
-
 // This is an unspecific happy-case test that runs through a particular distribution.
 #[tokio::test]
 async fn test_full_lifecycle() -> Result<(), TransportError> {
@@ -74,6 +49,9 @@ async fn test_full_lifecycle() -> Result<(), TransportError> {
             index: 0,
             end_ts: now_ts + 100,
             weight_ts: now_ts + 100,
+            claim_end_ts: 0,
+            keep_participants: false,
+            min_weight: 0,
             registrar: vsr.registrar,
             mint: mint0.pubkey,
             admin,
@@ -94,6 +72,7 @@ async fn test_full_lifecycle() -> Result<(), TransportError> {
     assert_eq!(info_event.participant_total_weight, 0);
     assert_eq!(info_event.distribution_amount, distribution_amount);
     assert!(!info_event.in_claim_phase);
+    assert!(!info_event.cancelled);
     // -1 is due to rounding down as end_ts > now_ts
     let weight0 = voter0.locked_amount * 12 / 60 - 1;
     assert_eq!(info_event.usable_weight, Some(weight0));
@@ -212,6 +191,19 @@ async fn test_full_lifecycle() -> Result<(), TransportError> {
         start_balance - distribution_amount + distribution_amount * weight0 / (weight0 + weight1)
     );
 
+    // closing is impossible while participant 1 hasn't claimed
+    assert!(send_tx(
+        solana,
+        CloseDistributionInstruction {
+            distribution,
+            admin,
+            target_token: payer_mint0_account,
+            rent_receiver: payer.pubkey(),
+        },
+    )
+    .await
+    .is_err());
+
     send_tx(
         solana,
         ClaimInstruction {
@@ -238,5 +230,26 @@ async fn test_full_lifecycle() -> Result<(), TransportError> {
     let balance = solana.token_account_balance(payer_mint0_account).await;
     assert_eq!(balance, start_balance - vault_balance);
 
+    //
+    // STEP 4: close the distribution, recovering the dust
+    //
+    solana.advance_by_slots(1).await;
+    send_tx(
+        solana,
+        CloseDistributionInstruction {
+            distribution,
+            admin,
+            target_token: payer_mint0_account,
+            rent_receiver: payer.pubkey(),
+        },
+    )
+    .await
+    .unwrap();
+
+    assert!(solana.get_account_data(vault).await.is_none());
+    assert!(solana.get_account_data(distribution).await.is_none());
+    let balance = solana.token_account_balance(payer_mint0_account).await;
+    assert_eq!(balance, start_balance);
+
     Ok(())
 }
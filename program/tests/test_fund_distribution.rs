@@ -0,0 +1,159 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transport::TransportError,
+};
+
+use distribute_by_locked_vote_weight::events;
+use distribute_by_locked_vote_weight::state::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_fund_distribution() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let funder1 = &context.users[1].key;
+    let mint0 = &context.mints[0];
+    let payer_mint0_account = context.users[0].token_accounts[0];
+    let funder1_mint0_account = context.users[1].token_accounts[0];
+
+    //
+    // SETUP: distribution
+    //
+    let accounts = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: 0,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: 0,
+            keep_participants: false,
+            min_weight: 0,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap();
+    let distribution = accounts.distribution;
+    let vault = accounts.vault;
+
+    //
+    // TEST: Funding is recorded and logged
+    //
+    for (funder, funder_token, amount) in [
+        (payer, payer_mint0_account, 1000),
+        (funder1, funder1_mint0_account, 500),
+    ] {
+        send_tx(
+            solana,
+            FundDistributionInstruction {
+                distribution,
+                funder,
+                funder_token,
+                amount,
+            },
+        )
+        .await
+        .unwrap();
+
+        let funded = solana
+            .program_log()
+            .iter()
+            .find_map(|log| deserialize_event::<events::Funded>(log))
+            .unwrap();
+        assert_eq!(funded.distribution, distribution);
+        assert_eq!(funded.funder, funder.pubkey());
+        assert_eq!(funded.amount, amount);
+    }
+
+    // direct transfers end up in the vault, but aren't recorded as funding
+    solana
+        .transfer_token(payer_mint0_account, payer, vault, 100)
+        .await;
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(distribution_data.funded_amount, 1500);
+    assert_eq!(distribution_data.funding_call_count, 2);
+    assert_eq!(solana.token_account_balance(vault).await, 1600);
+
+    //
+    // TEST: Funding can be restricted to the registration phase
+    //
+    send_tx(
+        solana,
+        SetFundingRestrictionInstruction {
+            distribution,
+            authority: admin,
+            registration_only: true,
+        },
+    )
+    .await
+    .unwrap();
+
+    solana.advance_by_slots(1).await;
+    send_tx(
+        solana,
+        FundDistributionInstruction {
+            distribution,
+            funder: payer,
+            funder_token: payer_mint0_account,
+            amount: 1000,
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin,
+            time_offset: 1000,
+        },
+    )
+    .await
+    .unwrap();
+
+    solana.advance_by_slots(1).await;
+    assert!(send_tx(
+        solana,
+        FundDistributionInstruction {
+            distribution,
+            funder: payer,
+            funder_token: payer_mint0_account,
+            amount: 1000,
+        },
+    )
+    .await
+    .is_err());
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(distribution_data.funded_amount, 2500);
+    assert_eq!(distribution_data.funding_call_count, 3);
+
+    Ok(())
+}
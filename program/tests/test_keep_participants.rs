@@ -0,0 +1,161 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transport::TransportError,
+};
+
+use distribute_by_locked_vote_weight::state::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_keep_participants() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let payer_mint0_account = context.users[0].token_accounts[0];
+    let target_mint0_account = context.users[1].token_accounts[0];
+    let target_start_balance = solana.token_account_balance(target_mint0_account).await;
+    let distribution_amount = 1000;
+    let voter = &vsr.voters[0];
+
+    //
+    // SETUP: distribution that keeps participants, with one participant
+    //
+    let accounts = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: 0,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: 0,
+            keep_participants: true,
+            min_weight: 0,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap();
+    let distribution = accounts.distribution;
+    let vault = accounts.vault;
+
+    solana
+        .transfer_token(payer_mint0_account, payer, vault, distribution_amount)
+        .await;
+
+    let participant = send_tx(
+        solana,
+        CreateParticipantInstruction {
+            distribution,
+            voter: voter.pubkey,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .participant;
+    let weight = solana.get_account::<Participant>(participant).await.weight;
+    let share = expected_share(distribution_amount, weight, weight as u128);
+
+    // claimed participants only
+    assert!(send_tx(
+        solana,
+        CloseClaimedParticipantInstruction {
+            participant,
+            voter_authority: &voter.authority,
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin,
+            time_offset: 1000,
+        },
+    )
+    .await
+    .unwrap();
+
+    //
+    // TEST: Claiming keeps the participant as a record
+    //
+    let claim = || ClaimInstruction {
+        participant,
+        voter_authority: &voter.authority,
+        target_token: target_mint0_account,
+        payer: payer.pubkey(),
+    };
+    send_tx(solana, claim()).await.unwrap();
+
+    let participant_data: Participant = solana.get_account(participant).await;
+    assert!(participant_data.is_claimed());
+    assert_eq!(participant_data.rewards.claimed_amount, share);
+    assert!(participant_data.claimed_ts >= now_ts + 1000);
+    assert_eq!(
+        solana.token_account_balance(target_mint0_account).await,
+        target_start_balance + share
+    );
+
+    // claiming again without new deposits transfers nothing
+    solana.advance_by_slots(1).await;
+    send_tx(solana, claim()).await.unwrap();
+    assert_eq!(
+        solana.token_account_balance(target_mint0_account).await,
+        target_start_balance + share
+    );
+
+    //
+    // TEST: The record outlives the distribution and can be closed later
+    //
+    send_tx(
+        solana,
+        CloseDistributionInstruction {
+            distribution,
+            admin,
+            target_token: target_mint0_account,
+            rent_receiver: payer.pubkey(),
+        },
+    )
+    .await
+    .unwrap();
+    assert!(solana.get_account_data(participant).await.is_some());
+
+    send_tx(
+        solana,
+        CloseClaimedParticipantInstruction {
+            participant,
+            voter_authority: &voter.authority,
+        },
+    )
+    .await
+    .unwrap();
+    assert!(solana.get_account_data(participant).await.is_none());
+
+    Ok(())
+}
@@ -0,0 +1,317 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transport::TransportError,
+};
+
+use distribute_by_locked_vote_weight::state::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_late_deposits() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let payer_mint0_account = context.users[0].token_accounts[0];
+    let distribution_amount = 1000;
+    let top_up_amount = 3000;
+    let voter0 = &vsr.voters[0];
+    let voter1 = &vsr.voters[1];
+
+    //
+    // SETUP: distribution that keeps participants, with two participants
+    //
+    let accounts = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: 0,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: 0,
+            keep_participants: true,
+            min_weight: 0,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap();
+    let distribution = accounts.distribution;
+    let vault = accounts.vault;
+
+    solana
+        .transfer_token(payer_mint0_account, payer, vault, distribution_amount)
+        .await;
+
+    let mut participants = vec![];
+    let mut targets = vec![];
+    for voter in [voter0, voter1] {
+        let participant = send_tx(
+            solana,
+            CreateParticipantInstruction {
+                distribution,
+                voter: voter.pubkey,
+                payer,
+            },
+        )
+        .await
+        .unwrap()
+        .participant;
+        participants.push(participant);
+        targets.push(
+            solana
+                .create_token_account(&voter.authority.pubkey(), mint0.pubkey)
+                .await,
+        );
+    }
+    let weight0 = solana
+        .get_account::<Participant>(participants[0])
+        .await
+        .weight;
+    let total_weight = solana
+        .get_account::<Distribution>(distribution)
+        .await
+        .participant_total_weight;
+
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin,
+            time_offset: 1000,
+        },
+    )
+    .await
+    .unwrap();
+
+    let claim = |i: usize| ClaimInstruction {
+        participant: participants[i],
+        voter_authority: if i == 0 {
+            &voter0.authority
+        } else {
+            &voter1.authority
+        },
+        target_token: targets[i],
+        payer: payer.pubkey(),
+    };
+
+    //
+    // TEST: The first claim pays out a share of the initial funding
+    //
+    send_tx(solana, claim(0)).await.unwrap();
+    let claimed0 = solana.token_account_balance(targets[0]).await;
+    assert_eq!(
+        claimed0,
+        expected_share(distribution_amount, weight0, total_weight)
+    );
+
+    //
+    // TEST: Tokens deposited after the first claim are shared by everyone
+    //
+    solana
+        .transfer_token(payer_mint0_account, payer, vault, top_up_amount)
+        .await;
+
+    send_tx(solana, claim(1)).await.unwrap();
+    solana.advance_by_slots(1).await;
+    send_tx(solana, claim(0)).await.unwrap();
+
+    let claimed0 = solana.token_account_balance(targets[0]).await;
+    let claimed1 = solana.token_account_balance(targets[1]).await;
+    let share0 = expected_share(distribution_amount + top_up_amount, weight0, total_weight);
+    let share1 = expected_share(
+        distribution_amount + top_up_amount,
+        (total_weight - weight0 as u128) as u64,
+        total_weight,
+    );
+    // the two funding rounds are rounded separately
+    assert!(share0 - claimed0 <= 1);
+    assert!(share1 - claimed1 <= 1);
+
+    // only rounding dust is left
+    let vault_balance = solana.token_account_balance(vault).await;
+    assert_eq!(
+        vault_balance,
+        distribution_amount + top_up_amount - claimed0 - claimed1
+    );
+    assert!(vault_balance <= 3);
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(
+        distribution_data.rewards.total_amount_to_distribute,
+        distribution_amount + top_up_amount
+    );
+    assert_eq!(
+        distribution_data.rewards.claimed_amount,
+        claimed0 + claimed1
+    );
+    assert_eq!(distribution_data.claim_count, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_late_deposits_after_closed_claims() -> Result<(), TransportError> {
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let payer_mint0_account = context.users[0].token_accounts[0];
+    let distribution_amount = 1000;
+    let top_up_amount = 3000;
+    let voter0 = &vsr.voters[0];
+    let voter1 = &vsr.voters[1];
+
+    //
+    // SETUP: distribution that closes participants on claim, with two participants
+    //
+    let accounts = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: 0,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: 0,
+            keep_participants: false,
+            min_weight: 0,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap();
+    let distribution = accounts.distribution;
+    let vault = accounts.vault;
+
+    solana
+        .transfer_token(payer_mint0_account, payer, vault, distribution_amount)
+        .await;
+
+    let mut participants = vec![];
+    let mut targets = vec![];
+    for voter in [voter0, voter1] {
+        let participant = send_tx(
+            solana,
+            CreateParticipantInstruction {
+                distribution,
+                voter: voter.pubkey,
+                payer,
+            },
+        )
+        .await
+        .unwrap()
+        .participant;
+        participants.push(participant);
+        targets.push(
+            solana
+                .create_token_account(&voter.authority.pubkey(), mint0.pubkey)
+                .await,
+        );
+    }
+    let weight1 = solana
+        .get_account::<Participant>(participants[1])
+        .await
+        .weight;
+    let total_weight = solana
+        .get_account::<Distribution>(distribution)
+        .await
+        .participant_total_weight;
+
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin,
+            time_offset: 1000,
+        },
+    )
+    .await
+    .unwrap();
+
+    //
+    // TEST: A closed participant's weight no longer counts
+    //
+    send_tx(
+        solana,
+        ClaimInstruction {
+            participant: participants[0],
+            voter_authority: &voter0.authority,
+            target_token: targets[0],
+            payer: payer.pubkey(),
+        },
+    )
+    .await
+    .unwrap();
+    assert!(solana.get_account_data(participants[0]).await.is_none());
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(
+        distribution_data.closed_participant_weight,
+        total_weight - weight1 as u128
+    );
+
+    //
+    // TEST: The remaining participant gets the whole top-up
+    //
+    solana
+        .transfer_token(payer_mint0_account, payer, vault, top_up_amount)
+        .await;
+    send_tx(
+        solana,
+        ClaimInstruction {
+            participant: participants[1],
+            voter_authority: &voter1.authority,
+            target_token: targets[1],
+            payer: payer.pubkey(),
+        },
+    )
+    .await
+    .unwrap();
+
+    let claimed0 = solana.token_account_balance(targets[0]).await;
+    let claimed1 = solana.token_account_balance(targets[1]).await;
+    let share1 = expected_share(distribution_amount, weight1, total_weight) + top_up_amount;
+    // the two funding rounds are rounded separately
+    assert!(share1 - claimed1 <= 1);
+    assert!(solana.token_account_balance(vault).await <= 3);
+    assert_eq!(
+        claimed0 + claimed1 + solana.token_account_balance(vault).await,
+        distribution_amount + top_up_amount
+    );
+
+    Ok(())
+}
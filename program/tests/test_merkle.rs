@@ -0,0 +1,214 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transport::TransportError,
+};
+
+use distribute_by_locked_vote_weight::merkle;
+use distribute_by_locked_vote_weight::state::*;
+use voter_stake_registry::state::LockupKind;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_merkle() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let payer_mint0_account = context.users[0].token_accounts[0];
+    let voter0 = &vsr.voters[0];
+    let voter1 = &vsr.voters[1];
+    let amounts = [600, 300];
+
+    //
+    // SETUP: merkle tree with an amount for each voter
+    //
+    let leaves = [
+        merkle::leaf_hash(0, &voter0.pubkey, amounts[0]),
+        merkle::leaf_hash(1, &voter1.pubkey, amounts[1]),
+    ];
+    let root = merkle::node_hash(&leaves[0], &leaves[1]);
+
+    let accounts = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: 0,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: 0,
+            keep_participants: false,
+            min_weight: 0,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap();
+    let distribution = accounts.distribution;
+    let vault = accounts.vault;
+
+    solana
+        .transfer_token(payer_mint0_account, payer, vault, amounts[0] + amounts[1])
+        .await;
+
+    send_tx(
+        solana,
+        SetMerkleRootInstruction {
+            distribution,
+            admin,
+            merkle_root: root,
+            leaf_count: 2,
+        },
+    )
+    .await
+    .unwrap();
+
+    // registration isn't possible in merkle distributions
+    assert!(send_tx(
+        solana,
+        CreateParticipantInstruction {
+            distribution,
+            voter: voter0.pubkey,
+            payer,
+        },
+    )
+    .await
+    .is_err());
+
+    // nor is requiring claims to be locked up, ClaimWithProof pays out directly
+    assert!(send_tx(
+        solana,
+        SetClaimLockupInstruction {
+            distribution,
+            admin,
+            lockup_kind: LockupKind::Cliff,
+            lockup_periods: 30,
+            lockup_required: true,
+        },
+    )
+    .await
+    .is_err());
+
+    let mut targets = vec![];
+    for voter in [voter0, voter1] {
+        targets.push(
+            solana
+                .create_token_account(&voter.authority.pubkey(), mint0.pubkey)
+                .await,
+        );
+    }
+    let claim = |i: usize, amount: u64, proof: [u8; 32]| ClaimWithProofInstruction {
+        distribution,
+        index: i as u32,
+        amount,
+        proof: vec![proof],
+        voter: vsr.voters[i].pubkey,
+        voter_authority: &vsr.voters[i].authority,
+        target_token: targets[i],
+        payer,
+    };
+
+    //
+    // TEST: Claiming is only possible in the claim phase
+    //
+    assert!(send_tx(solana, claim(0, amounts[0], leaves[1]))
+        .await
+        .is_err());
+
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin,
+            time_offset: 1000,
+        },
+    )
+    .await
+    .unwrap();
+
+    //
+    // TEST: Claims need a valid proof
+    //
+    assert!(send_tx(solana, claim(0, amounts[0] + 1, leaves[1]))
+        .await
+        .is_err());
+    assert!(send_tx(solana, claim(0, amounts[0], leaves[0]))
+        .await
+        .is_err());
+
+    // another voter can't claim the leaf
+    assert!(send_tx(
+        solana,
+        ClaimWithProofInstruction {
+            voter: voter0.pubkey,
+            voter_authority: &voter1.authority,
+            ..claim(0, amounts[0], leaves[1])
+        },
+    )
+    .await
+    .is_err());
+
+    //
+    // TEST: Each leaf can be claimed once
+    //
+    send_tx(solana, claim(0, amounts[0], leaves[1]))
+        .await
+        .unwrap();
+    assert_eq!(solana.token_account_balance(targets[0]).await, amounts[0]);
+
+    solana.advance_by_slots(1).await;
+    assert!(send_tx(solana, claim(0, amounts[0], leaves[1]))
+        .await
+        .is_err());
+
+    send_tx(solana, claim(1, amounts[1], leaves[0]))
+        .await
+        .unwrap();
+    assert_eq!(solana.token_account_balance(targets[1]).await, amounts[1]);
+    assert_eq!(solana.token_account_balance(vault).await, 0);
+
+    let claim_bitmap = Pubkey::find_program_address(
+        &[
+            distribution.as_ref(),
+            b"claim_bitmap".as_ref(),
+            &0u32.to_le_bytes(),
+        ],
+        &distribute_by_locked_vote_weight::id(),
+    )
+    .0;
+    let claim_bitmap_data: ClaimBitmap = solana.get_account(claim_bitmap).await;
+    assert!(claim_bitmap_data.is_claimed(0));
+    assert!(claim_bitmap_data.is_claimed(1));
+    assert!(!claim_bitmap_data.is_claimed(2));
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(
+        distribution_data.rewards.claimed_amount,
+        amounts[0] + amounts[1]
+    );
+
+    Ok(())
+}
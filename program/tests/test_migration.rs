@@ -0,0 +1,234 @@
+#![cfg(feature = "test-bpf")]
+
+use anchor_lang::Discriminator;
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transport::TransportError,
+};
+
+use distribute_by_locked_vote_weight::state::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_migration() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    let admin = &Keypair::new();
+    let participant_payer = &Keypair::new();
+    let voter0 = &vsr.voters[0];
+    // -1 is due to rounding down as end_ts > now_ts
+    let weight0 = voter0.locked_amount * 12 / 60 - 1;
+    let weight1 = vsr.voters[1].locked_amount * 12 / 60 - 1;
+    let total_weight = (weight0 + weight1) as u128;
+
+    //
+    // SETUP: a distribution of the first program version in its claim phase,
+    // where voter1 already claimed their share of the snapshot
+    //
+    let snapshot_amount: u64 = 894;
+    let claimed_amount = expected_share(snapshot_amount, weight1, total_weight);
+    let (distribution, bump) = Pubkey::find_program_address(
+        &[
+            b"distribution".as_ref(),
+            admin.pubkey().as_ref(),
+            &0u64.to_le_bytes(),
+        ],
+        &distribute_by_locked_vote_weight::id(),
+    );
+    let vault =
+        spl_associated_token_account::get_associated_token_address(&distribution, &MANGO_MINT_PK);
+    test_config.add_packable_account(
+        vault,
+        spl_token::state::Account {
+            mint: *MANGO_MINT_PK,
+            owner: distribution,
+            amount: snapshot_amount - claimed_amount,
+            state: spl_token::state::AccountState::Initialized,
+            ..spl_token::state::Account::default()
+        },
+        spl_token::id(),
+    );
+
+    let mut distribution_data = Distribution::discriminator().to_vec();
+    distribution_data.extend_from_slice(admin.pubkey().as_ref());
+    distribution_data.extend_from_slice(vsr.registrar.as_ref());
+    distribution_data.extend_from_slice(MANGO_MINT_PK.as_ref());
+    distribution_data.extend_from_slice(vault.as_ref());
+    distribution_data.extend_from_slice(&0u64.to_le_bytes()); // index
+    distribution_data.extend_from_slice(&(now_ts - 100).to_le_bytes()); // registration_end_ts
+    distribution_data.extend_from_slice(&(now_ts - 100).to_le_bytes()); // weight_ts
+    distribution_data.extend_from_slice(&total_weight.to_le_bytes());
+    distribution_data.extend_from_slice(&snapshot_amount.to_le_bytes());
+    distribution_data.extend_from_slice(&0i64.to_le_bytes()); // time_offset
+    distribution_data.extend_from_slice(&2u32.to_le_bytes()); // participant_count
+    distribution_data.extend_from_slice(&1u32.to_le_bytes()); // claim_count
+    distribution_data.push(bump);
+    distribution_data.extend_from_slice(&[0; 39]);
+    assert_eq!(distribution_data.len(), 8 + LEGACY_DISTRIBUTION_SIZE);
+    test_config.add_raw_account(
+        distribution,
+        distribution_data,
+        distribute_by_locked_vote_weight::id(),
+    );
+
+    let participant0 = Pubkey::find_program_address(
+        &[
+            distribution.as_ref(),
+            b"participant".as_ref(),
+            voter0.pubkey.as_ref(),
+        ],
+        &distribute_by_locked_vote_weight::id(),
+    )
+    .0;
+    let mut participant_data = Participant::discriminator().to_vec();
+    participant_data.extend_from_slice(distribution.as_ref());
+    participant_data.extend_from_slice(voter0.pubkey.as_ref());
+    participant_data.extend_from_slice(voter0.authority.pubkey().as_ref());
+    participant_data.extend_from_slice(participant_payer.pubkey().as_ref());
+    participant_data.extend_from_slice(&weight0.to_le_bytes());
+    assert_eq!(participant_data.len(), 8 + LEGACY_PARTICIPANT_SIZE);
+    test_config.add_raw_account(
+        participant0,
+        participant_data,
+        distribute_by_locked_vote_weight::id(),
+    );
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let payer_mint0_account = context.users[0].token_accounts[0];
+
+    //
+    // TEST: The vault must match
+    //
+    assert!(send_tx(
+        solana,
+        MigrateDistributionInstruction {
+            distribution,
+            vault: payer_mint0_account,
+            payer,
+        },
+    )
+    .await
+    .is_err());
+
+    //
+    // TEST: Migrate the distribution
+    //
+    send_tx(
+        solana,
+        MigrateDistributionInstruction {
+            distribution,
+            vault,
+            payer,
+        },
+    )
+    .await
+    .unwrap();
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(distribution_data.admin, admin.pubkey());
+    assert_eq!(distribution_data.creator, admin.pubkey());
+    assert_eq!(distribution_data.vault, vault);
+    assert_eq!(distribution_data.registration_end_ts, now_ts - 100);
+    assert_eq!(distribution_data.participant_total_weight, total_weight);
+    assert_eq!(distribution_data.claim_count, 1);
+    assert_eq!(distribution_data.rewards.claimed_amount, claimed_amount);
+    assert_eq!(
+        distribution_data.rewards.total_amount_to_distribute,
+        snapshot_amount
+    );
+    assert_eq!(
+        solana.get_account_lamports(distribution).await,
+        solana_sdk::rent::Rent::default().minimum_balance(8 + std::mem::size_of::<Distribution>())
+    );
+
+    // a second migration fails
+    solana.advance_by_slots(1).await;
+    assert!(send_tx(
+        solana,
+        MigrateDistributionInstruction {
+            distribution,
+            vault,
+            payer,
+        },
+    )
+    .await
+    .is_err());
+
+    //
+    // TEST: Migrate the participant and claim the rest of the snapshot
+    //
+    send_tx(
+        solana,
+        MigrateParticipantInstruction {
+            participant: participant0,
+            payer,
+        },
+    )
+    .await
+    .unwrap();
+
+    let participant_data: Participant = solana.get_account(participant0).await;
+    assert_eq!(participant_data.voter, voter0.pubkey);
+    assert_eq!(participant_data.payer, participant_payer.pubkey());
+    assert_eq!(participant_data.weight, weight0);
+    assert_eq!(
+        solana.get_account_lamports(participant0).await,
+        solana_sdk::rent::Rent::default().minimum_balance(8 + std::mem::size_of::<Participant>())
+    );
+
+    // a second migration fails
+    solana.advance_by_slots(1).await;
+    assert!(send_tx(
+        solana,
+        MigrateParticipantInstruction {
+            participant: participant0,
+            payer,
+        },
+    )
+    .await
+    .is_err());
+
+    let target = solana
+        .create_token_account(&voter0.authority.pubkey(), mint0.pubkey)
+        .await;
+    send_tx(
+        solana,
+        ClaimInstruction {
+            participant: participant0,
+            voter_authority: &voter0.authority,
+            target_token: target,
+            payer: participant_payer.pubkey(),
+        },
+    )
+    .await
+    .unwrap();
+
+    let share0 = expected_share(snapshot_amount, weight0, total_weight);
+    assert_eq!(solana.token_account_balance(target).await, share0);
+    assert_eq!(
+        solana.token_account_balance(vault).await,
+        snapshot_amount - claimed_amount - share0
+    );
+
+    Ok(())
+}
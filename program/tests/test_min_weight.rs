@@ -0,0 +1,115 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, transport::TransportError};
+
+use distribute_by_locked_vote_weight::state::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_min_weight() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let voter0 = &vsr.voters[0];
+    let voter1 = &vsr.voters[1];
+    // -1 is due to rounding down as end_ts > now_ts
+    let weight0 = voter0.locked_amount * 12 / 60 - 1;
+    let weight1 = voter1.locked_amount * 12 / 60 - 1;
+    let min_weight = weight1 + 1;
+    assert!(weight0 >= min_weight);
+
+    let distribution = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: 0,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: 0,
+            keep_participants: false,
+            min_weight,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .distribution;
+
+    //
+    // TEST: Info reports the minimum weight
+    //
+    let info_event = get_info(solana, distribution, voter1.pubkey).await;
+    assert_eq!(info_event.min_weight, min_weight);
+    assert_eq!(info_event.usable_weight, Some(weight1));
+
+    //
+    // TEST: An absolute weight cap can't be below the minimum
+    //
+    assert!(send_tx(
+        solana,
+        SetWeightCapInstruction {
+            distribution,
+            admin,
+            max_weight_per_participant: min_weight - 1,
+            in_bps: false,
+        },
+    )
+    .await
+    .is_err());
+
+    //
+    // TEST: Voters below the minimum can't register
+    //
+    assert!(send_tx(
+        solana,
+        CreateParticipantInstruction {
+            distribution,
+            voter: voter1.pubkey,
+            payer,
+        },
+    )
+    .await
+    .is_err());
+
+    let participant = send_tx(
+        solana,
+        CreateParticipantInstruction {
+            distribution,
+            voter: voter0.pubkey,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .participant;
+    let participant_data: Participant = solana.get_account(participant).await;
+    assert_eq!(participant_data.weight, weight0);
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(distribution_data.participant_count, 1);
+    assert_eq!(distribution_data.participant_total_weight, weight0 as u128);
+
+    Ok(())
+}
@@ -0,0 +1,192 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transport::TransportError,
+};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_reclaim_remainder() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let payer_mint0_account = context.users[0].token_accounts[0];
+    let admin_mint0_account = context.users[1].token_accounts[0];
+    let admin_start_balance = solana.token_account_balance(admin_mint0_account).await;
+    let distribution_amount = 1000;
+    let voter0 = &vsr.voters[0];
+    let voter1 = &vsr.voters[1];
+
+    //
+    // SETUP: distribution with a claim deadline and two participants
+    //
+    let accounts = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: 0,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: now_ts + 200,
+            keep_participants: false,
+            min_weight: 0,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap();
+    let distribution = accounts.distribution;
+    let vault = accounts.vault;
+
+    solana
+        .transfer_token(payer_mint0_account, payer, vault, distribution_amount)
+        .await;
+
+    let participant0 = send_tx(
+        solana,
+        CreateParticipantInstruction {
+            distribution,
+            voter: voter0.pubkey,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .participant;
+    let participant1 = send_tx(
+        solana,
+        CreateParticipantInstruction {
+            distribution,
+            voter: voter1.pubkey,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .participant;
+
+    let reclaim = || ReclaimRemainderInstruction {
+        distribution,
+        admin,
+        target_token: admin_mint0_account,
+        sol_destination: payer.pubkey(),
+    };
+
+    //
+    // TEST: Reclaiming is impossible before claim_end_ts
+    //
+    assert!(send_tx(solana, reclaim()).await.is_err());
+
+    // claim phase: participant 0 claims, participant 1 doesn't
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin,
+            time_offset: 150,
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        ClaimInstruction {
+            participant: participant0,
+            voter_authority: &voter0.authority,
+            target_token: payer_mint0_account,
+            payer: payer.pubkey(),
+        },
+    )
+    .await
+    .unwrap();
+    solana.advance_by_slots(1).await;
+    assert!(send_tx(solana, reclaim()).await.is_err());
+
+    //
+    // TEST: After claim_end_ts, claiming is impossible and reclaiming works
+    //
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin,
+            time_offset: 1000,
+        },
+    )
+    .await
+    .unwrap();
+
+    assert!(send_tx(
+        solana,
+        ClaimInstruction {
+            participant: participant1,
+            voter_authority: &voter1.authority,
+            target_token: payer_mint0_account,
+            payer: payer.pubkey(),
+        },
+    )
+    .await
+    .is_err());
+
+    let vault_balance = solana.token_account_balance(vault).await;
+    assert!(vault_balance > 0);
+
+    let close_orphaned = || CloseOrphanedParticipantInstruction {
+        participant: participant1,
+        voter_authority: &voter1.authority,
+    };
+    assert!(send_tx(solana, close_orphaned()).await.is_err());
+
+    // a random signer can't reclaim
+    assert!(send_tx(
+        solana,
+        ReclaimRemainderInstruction {
+            admin: &Keypair::new(),
+            ..reclaim()
+        }
+    )
+    .await
+    .is_err());
+
+    solana.advance_by_slots(1).await;
+    send_tx(solana, reclaim()).await.unwrap();
+
+    assert_eq!(
+        solana.token_account_balance(admin_mint0_account).await,
+        admin_start_balance + vault_balance
+    );
+    assert!(solana.get_account_data(vault).await.is_none());
+    assert!(solana.get_account_data(distribution).await.is_none());
+
+    //
+    // TEST: The unclaimed participant can be closed once the distribution is gone
+    //
+    solana.advance_by_slots(1).await;
+    send_tx(solana, close_orphaned()).await.unwrap();
+    assert!(solana.get_account_data(participant1).await.is_none());
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transport::TransportError,
+};
+
+use distribute_by_locked_vote_weight::state::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_refresh_participant() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let payer_mint0_account = context.users[0].token_accounts[0];
+    let distribution_amount = 1000;
+    let voter0 = &vsr.voters[0];
+    let voter1 = &vsr.voters[1];
+
+    //
+    // SETUP: distribution with two participants
+    //
+    let accounts = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: 0,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: 0,
+            keep_participants: false,
+            min_weight: 50,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap();
+    let distribution = accounts.distribution;
+    let vault = accounts.vault;
+
+    solana
+        .transfer_token(payer_mint0_account, payer, vault, distribution_amount)
+        .await;
+
+    let mut participants = vec![];
+    for voter in [voter0, voter1] {
+        let participant = send_tx(
+            solana,
+            CreateParticipantInstruction {
+                distribution,
+                voter: voter.pubkey,
+                payer,
+            },
+        )
+        .await
+        .unwrap()
+        .participant;
+        participants.push(participant);
+    }
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(distribution_data.participant_total_weight, 199 + 99);
+
+    //
+    // TEST: After a clawback, UpdateParticipant fails but refreshing reduces the weight
+    //
+    vsr_client::claw_back(solana, voter0, 500).await;
+
+    assert!(send_tx(
+        solana,
+        UpdateParticipantInstruction {
+            participant: participants[0],
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        RefreshParticipantInstruction {
+            participant: participants[0],
+        },
+    )
+    .await
+    .unwrap();
+
+    let participant_data: Participant = solana.get_account(participants[0]).await;
+    assert_eq!(participant_data.weight, 99);
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(distribution_data.participant_total_weight, 99 + 99);
+
+    //
+    // TEST: Below min_weight, the participant keeps no weight
+    //
+    vsr_client::claw_back(solana, voter1, 200).await;
+    send_tx(
+        solana,
+        RefreshParticipantInstruction {
+            participant: participants[1],
+        },
+    )
+    .await
+    .unwrap();
+
+    let participant_data: Participant = solana.get_account(participants[1]).await;
+    assert_eq!(participant_data.weight, 0);
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(distribution_data.participant_total_weight, 99);
+
+    //
+    // TEST: Weights are fixed in the claim phase, the clawed-back voter gets nothing
+    //
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin,
+            time_offset: 150,
+        },
+    )
+    .await
+    .unwrap();
+
+    vsr_client::claw_back(solana, voter0, 1000).await;
+    assert!(send_tx(
+        solana,
+        RefreshParticipantInstruction {
+            participant: participants[0],
+        },
+    )
+    .await
+    .is_err());
+
+    let payer_start_balance = solana.token_account_balance(payer_mint0_account).await;
+    for (participant, voter) in participants.iter().zip([voter0, voter1]) {
+        send_tx(
+            solana,
+            ClaimInstruction {
+                participant: *participant,
+                voter_authority: &voter.authority,
+                target_token: payer_mint0_account,
+                payer: payer.pubkey(),
+            },
+        )
+        .await
+        .unwrap();
+    }
+    // everything but the rounding dust went to participant 0
+    let claimed = solana.token_account_balance(payer_mint0_account).await - payer_start_balance;
+    assert!(claimed <= distribution_amount && claimed >= distribution_amount - 1);
+
+    Ok(())
+}
@@ -0,0 +1,219 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transport::TransportError,
+};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_reward_vaults() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let mint1 = &context.mints[1];
+    let payer_mint0_account = context.users[0].token_accounts[0];
+    let payer_mint1_account = context.users[0].token_accounts[1];
+    let distribution_amount0 = 1000;
+    let distribution_amount1 = 500;
+    let voter0 = &vsr.voters[0];
+    let voter1 = &vsr.voters[1];
+
+    //
+    // SETUP: distribution with a reward vault for mint1
+    //
+    let accounts = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: 0,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: 0,
+            keep_participants: false,
+            min_weight: 0,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap();
+    let distribution = accounts.distribution;
+    let vault0 = accounts.vault;
+
+    let vault1 = send_tx(
+        solana,
+        AddRewardVaultInstruction {
+            distribution,
+            authority: admin,
+            mint: mint1.pubkey,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .vault;
+
+    // the main mint and already added mints can't be added again
+    for mint in [mint0, mint1] {
+        assert!(send_tx(
+            solana,
+            AddRewardVaultInstruction {
+                distribution,
+                authority: admin,
+                mint: mint.pubkey,
+                payer,
+            },
+        )
+        .await
+        .is_err());
+    }
+
+    solana
+        .transfer_token(payer_mint0_account, payer, vault0, distribution_amount0)
+        .await;
+    solana
+        .transfer_token(payer_mint1_account, payer, vault1, distribution_amount1)
+        .await;
+
+    let info_event = get_info(solana, distribution, voter0.pubkey).await;
+    assert_eq!(info_event.distribution_amount, distribution_amount0);
+    assert_eq!(info_event.reward_vault_amounts.len(), 1);
+    assert_eq!(info_event.reward_vault_amounts[0].mint, mint1.pubkey);
+    assert_eq!(
+        info_event.reward_vault_amounts[0].amount,
+        distribution_amount1
+    );
+
+    let mut participants = vec![];
+    for voter in [voter0, voter1] {
+        let participant = send_tx(
+            solana,
+            CreateParticipantInstruction {
+                distribution,
+                voter: voter.pubkey,
+                payer,
+            },
+        )
+        .await
+        .unwrap()
+        .participant;
+        participants.push(participant);
+    }
+
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin,
+            time_offset: 1000,
+        },
+    )
+    .await
+    .unwrap();
+
+    // adding reward vaults is only possible during registration
+    assert!(send_tx(
+        solana,
+        AddRewardVaultInstruction {
+            distribution,
+            authority: admin,
+            mint: context.mints[2].pubkey,
+            payer,
+        },
+    )
+    .await
+    .is_err());
+
+    //
+    // TEST: Claim pays out both mints
+    //
+    let mut total_claimed0 = 0;
+    let mut total_claimed1 = 0;
+    for (participant, voter) in participants.iter().zip([voter0, voter1]) {
+        let target0 = solana
+            .create_token_account(&voter.authority.pubkey(), mint0.pubkey)
+            .await;
+        let target1 = solana
+            .create_associated_token_account(&voter.authority.pubkey(), mint1.pubkey)
+            .await;
+
+        send_tx(
+            solana,
+            ClaimInstruction {
+                participant: *participant,
+                voter_authority: &voter.authority,
+                target_token: target0,
+                payer: payer.pubkey(),
+            },
+        )
+        .await
+        .unwrap();
+
+        let claimed0 = solana.token_account_balance(target0).await;
+        let claimed1 = solana.token_account_balance(target1).await;
+        assert!(claimed0 > 0);
+        assert!(claimed1 > 0);
+        // both mints are split by the same weights
+        assert!((claimed0 as i64 - 2 * claimed1 as i64).abs() <= 2);
+        total_claimed0 += claimed0;
+        total_claimed1 += claimed1;
+    }
+
+    assert_eq!(
+        solana.token_account_balance(vault0).await,
+        distribution_amount0 - total_claimed0
+    );
+    assert_eq!(
+        solana.token_account_balance(vault1).await,
+        distribution_amount1 - total_claimed1
+    );
+
+    //
+    // TEST: Closing the distribution sweeps and closes the reward vault
+    //
+    let admin_mint1_account = solana
+        .create_associated_token_account(&admin.pubkey(), mint1.pubkey)
+        .await;
+    send_tx(
+        solana,
+        CloseDistributionInstruction {
+            distribution,
+            admin,
+            target_token: payer_mint0_account,
+            rent_receiver: payer.pubkey(),
+        },
+    )
+    .await
+    .unwrap();
+
+    assert!(solana.get_account_data(vault0).await.is_none());
+    assert!(solana.get_account_data(vault1).await.is_none());
+    assert_eq!(
+        solana.token_account_balance(admin_mint1_account).await,
+        distribution_amount1 - total_claimed1
+    );
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, transport::TransportError};
+
+use distribute_by_locked_vote_weight::state::*;
+
+mod program_test;
+
+// Test builds allow the distribution admin to move time; see the unit tests in
+// state/distribution.rs for the mainnet build behavior.
+#[tokio::test]
+async fn test_time_offset() -> Result<(), TransportError> {
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+
+    let distribution = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: 0,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: 0,
+            keep_participants: false,
+            min_weight: 0,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .distribution;
+
+    // only the admin may set the offset
+    assert!(send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin: &Keypair::new(),
+            time_offset: 1000,
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin,
+            time_offset: 1000,
+        },
+    )
+    .await
+    .unwrap();
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(distribution_data.time_offset, 1000);
+
+    // the offset moves the distribution into the claim phase
+    assert!(send_tx(
+        solana,
+        CreateParticipantInstruction {
+            distribution,
+            voter: vsr.voters[0].pubkey,
+            payer,
+        },
+    )
+    .await
+    .is_err());
+
+    Ok(())
+}
@@ -0,0 +1,261 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program::system_instruction;
+use solana_program_test::*;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transport::TransportError,
+};
+
+use distribute_by_locked_vote_weight::state::*;
+
+mod program_test;
+
+/// A single key acting as admin, payer, funder and rent destination, the way
+/// a governance treasury would own a distribution.
+///
+/// A keypair stands in for the treasury. No proposals are executed, so this
+/// doesn't cover a PDA signing through invoke_signed.
+#[tokio::test]
+async fn test_treasury_admin() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let treasury = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let payer_mint0_account = context.users[0].token_accounts[0];
+    let distribution_amount = 1000;
+    let voter0 = &vsr.voters[0];
+    let voter1 = &vsr.voters[1];
+
+    //
+    // SETUP: a treasury holding SOL and tokens
+    //
+    solana
+        .process_transaction(
+            &[system_instruction::transfer(
+                &payer.pubkey(),
+                &treasury.pubkey(),
+                1_000_000_000,
+            )],
+            Some(&[payer]),
+        )
+        .await
+        .unwrap();
+    let treasury_mint0_account = solana
+        .create_associated_token_account(&treasury.pubkey(), mint0.pubkey)
+        .await;
+    solana
+        .transfer_token(
+            payer_mint0_account,
+            payer,
+            treasury_mint0_account,
+            distribution_amount,
+        )
+        .await;
+
+    //
+    // TEST: Proposal creating the distribution, paid by the treasury
+    //
+    let accounts = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: 0,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: now_ts + 200,
+            keep_participants: false,
+            min_weight: 0,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin: treasury,
+            payer: treasury,
+        },
+    )
+    .await
+    .unwrap();
+    let distribution = accounts.distribution;
+    let vault = accounts.vault;
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(distribution_data.admin, treasury.pubkey());
+
+    //
+    // TEST: Proposal funding the distribution from the treasury
+    //
+    send_tx(
+        solana,
+        FundDistributionInstruction {
+            distribution,
+            funder: treasury,
+            funder_token: treasury_mint0_account,
+            amount: distribution_amount,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        solana.token_account_balance(vault).await,
+        distribution_amount
+    );
+    assert_eq!(
+        solana.token_account_balance(treasury_mint0_account).await,
+        0
+    );
+
+    let mut participants = vec![];
+    for voter in [voter0, voter1] {
+        let participant = send_tx(
+            solana,
+            CreateParticipantInstruction {
+                distribution,
+                voter: voter.pubkey,
+                payer,
+            },
+        )
+        .await
+        .unwrap()
+        .participant;
+        participants.push(participant);
+    }
+
+    //
+    // TEST: Proposal extending the claim deadline
+    //
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin: treasury,
+            time_offset: 150,
+        },
+    )
+    .await
+    .unwrap();
+
+    // the deadline can't be moved forward or added
+    for claim_end_ts in [now_ts + 199, now_ts + 150] {
+        assert!(send_tx(
+            solana,
+            SetClaimEndInstruction {
+                distribution,
+                admin: treasury,
+                claim_end_ts,
+            },
+        )
+        .await
+        .is_err());
+    }
+    // only the admin can extend it
+    assert!(send_tx(
+        solana,
+        SetClaimEndInstruction {
+            distribution,
+            admin: payer,
+            claim_end_ts: now_ts + 300,
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        SetClaimEndInstruction {
+            distribution,
+            admin: treasury,
+            claim_end_ts: now_ts + 300,
+        },
+    )
+    .await
+    .unwrap();
+
+    // claiming works after the original deadline
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin: treasury,
+            time_offset: 250,
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        ClaimInstruction {
+            participant: participants[0],
+            voter_authority: &voter0.authority,
+            target_token: payer_mint0_account,
+            payer: payer.pubkey(),
+        },
+    )
+    .await
+    .unwrap();
+
+    //
+    // TEST: Proposal reclaiming the remainder into the treasury
+    //
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin: treasury,
+            time_offset: 1000,
+        },
+    )
+    .await
+    .unwrap();
+
+    // once the claim phase ended, it can't be extended anymore
+    assert!(send_tx(
+        solana,
+        SetClaimEndInstruction {
+            distribution,
+            admin: treasury,
+            claim_end_ts: now_ts + 2000,
+        },
+    )
+    .await
+    .is_err());
+
+    let vault_balance = solana.token_account_balance(vault).await;
+    assert!(vault_balance > 0);
+    let treasury_lamports = solana.get_account_lamports(treasury.pubkey()).await;
+
+    send_tx(
+        solana,
+        ReclaimRemainderInstruction {
+            distribution,
+            admin: treasury,
+            target_token: treasury_mint0_account,
+            sol_destination: treasury.pubkey(),
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        solana.token_account_balance(treasury_mint0_account).await,
+        vault_balance
+    );
+    assert!(solana.get_account_lamports(treasury.pubkey()).await > treasury_lamports);
+    assert!(solana.get_account_data(distribution).await.is_none());
+
+    Ok(())
+}
@@ -0,0 +1,210 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transport::TransportError,
+};
+
+use distribute_by_locked_vote_weight::state::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_update_distribution_config() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let voter0 = &vsr.voters[0];
+    let voter1 = &vsr.voters[1];
+
+    //
+    // SETUP: distribution
+    //
+    let distribution = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: 0,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: now_ts + 300,
+            keep_participants: false,
+            min_weight: 0,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .distribution;
+
+    let update = |registration_end_ts: u64, weight_ts: u64| UpdateDistributionConfigInstruction {
+        distribution,
+        admin,
+        registration_end_ts,
+        weight_ts,
+    };
+
+    //
+    // TEST: Invalid updates
+    //
+    // only the admin can update
+    assert!(send_tx(
+        solana,
+        UpdateDistributionConfigInstruction {
+            admin: payer,
+            ..update(now_ts + 200, now_ts + 250)
+        },
+    )
+    .await
+    .is_err());
+    // the registration phase can't be shortened
+    assert!(send_tx(solana, update(now_ts + 50, 0)).await.is_err());
+    // weight_ts must not lie within the registration phase
+    assert!(send_tx(solana, update(now_ts + 200, 0)).await.is_err());
+    assert!(send_tx(solana, update(0, now_ts + 50)).await.is_err());
+    // the registration phase must end before claim_end_ts
+    assert!(send_tx(solana, update(now_ts + 300, now_ts + 300))
+        .await
+        .is_err());
+
+    //
+    // TEST: Postpone the registration end, moving weight_ts later as well
+    //
+    send_tx(solana, update(now_ts + 200, now_ts + 250))
+        .await
+        .unwrap();
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(distribution_data.registration_end_ts, now_ts + 200);
+    assert_eq!(distribution_data.weight_ts, now_ts + 250);
+
+    let participant0 = send_tx(
+        solana,
+        CreateParticipantInstruction {
+            distribution,
+            voter: voter0.pubkey,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .participant;
+    let participant0_weight = solana.get_account::<Participant>(participant0).await.weight;
+
+    //
+    // TEST: Moving weight_ts earlier keeps the registered weights
+    //
+    send_tx(solana, update(0, now_ts + 220)).await.unwrap();
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(distribution_data.weight_ts, now_ts + 220);
+    assert_eq!(
+        distribution_data.participant_total_weight,
+        participant0_weight as u128
+    );
+
+    //
+    // TEST: Moving weight_ts later makes registered weights stale until they
+    // are re-evaluated
+    //
+    send_tx(solana, update(0, now_ts + 260)).await.unwrap();
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    let participant0_data: Participant = solana.get_account(participant0).await;
+    assert_eq!(distribution_data.weight_ts, now_ts + 260);
+    assert_eq!(distribution_data.participant_total_weight, 0);
+    assert!(distribution_data.participant_weight_is_stale(&participant0_data));
+    assert_eq!(distribution_data.participant_weight(&participant0_data), 0);
+
+    // the constant lockup has the same weight at both times
+    send_tx(
+        solana,
+        UpdateParticipantInstruction {
+            participant: participant0,
+        },
+    )
+    .await
+    .unwrap();
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    let participant0_data: Participant = solana.get_account(participant0).await;
+    assert!(!distribution_data.participant_weight_is_stale(&participant0_data));
+    assert_eq!(participant0_data.weight, participant0_weight);
+    assert_eq!(
+        distribution_data.participant_total_weight,
+        participant0_weight as u128
+    );
+
+    //
+    // TEST: Registration stays open until the new end
+    //
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin,
+            time_offset: 150,
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        CreateParticipantInstruction {
+            distribution,
+            voter: voter1.pubkey,
+            payer,
+        },
+    )
+    .await
+    .unwrap();
+    assert!(send_tx(
+        solana,
+        ClaimInstruction {
+            participant: participant0,
+            voter_authority: &voter0.authority,
+            target_token: context.users[0].token_accounts[0],
+            payer: payer.pubkey(),
+        },
+    )
+    .await
+    .is_err());
+
+    //
+    // TEST: No updates after the registration phase
+    //
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin,
+            time_offset: 210,
+        },
+    )
+    .await
+    .unwrap();
+    assert!(send_tx(solana, update(now_ts + 250, 0)).await.is_err());
+
+    Ok(())
+}
@@ -44,6 +44,9 @@ async fn test_update_weight() -> Result<(), TransportError> {
             index: 0,
             end_ts: now_ts + 100,
             weight_ts: now_ts + 100,
+            claim_end_ts: 0,
+            keep_participants: false,
+            min_weight: 0,
             registrar: vsr.registrar,
             mint: mint0.pubkey,
             admin,
@@ -0,0 +1,179 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transport::TransportError,
+};
+
+use distribute_by_locked_vote_weight::state::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_verification_window() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let payer_mint0_account = context.users[0].token_accounts[0];
+    let distribution_amount = 1000;
+    let voter0 = &vsr.voters[0];
+    let voter1 = &vsr.voters[1];
+
+    //
+    // SETUP: distribution with a verification window and two participants
+    //
+    let accounts = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: 0,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: now_ts + 300,
+            keep_participants: false,
+            min_weight: 0,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap();
+    let distribution = accounts.distribution;
+    let vault = accounts.vault;
+
+    // the window must end before claim_end_ts
+    assert!(send_tx(
+        solana,
+        SetVerificationWindowInstruction {
+            distribution,
+            admin,
+            verification_duration: 200,
+        },
+    )
+    .await
+    .is_err());
+    send_tx(
+        solana,
+        SetVerificationWindowInstruction {
+            distribution,
+            admin,
+            verification_duration: 100,
+        },
+    )
+    .await
+    .unwrap();
+
+    solana
+        .transfer_token(payer_mint0_account, payer, vault, distribution_amount)
+        .await;
+
+    let mut participants = vec![];
+    for voter in [voter0, voter1] {
+        let participant = send_tx(
+            solana,
+            CreateParticipantInstruction {
+                distribution,
+                voter: voter.pubkey,
+                payer,
+            },
+        )
+        .await
+        .unwrap()
+        .participant;
+        participants.push(participant);
+    }
+
+    let claim = |i: usize| ClaimInstruction {
+        participant: participants[i],
+        voter_authority: &vsr.voters[i].authority,
+        target_token: payer_mint0_account,
+        payer: payer.pubkey(),
+    };
+    let challenge = |i: usize| ChallengeParticipantInstruction {
+        participant: participants[i],
+    };
+
+    //
+    // TEST: No challenges during registration
+    //
+    vsr_client::claw_back(solana, voter0, 500).await;
+    assert!(send_tx(solana, challenge(0)).await.is_err());
+
+    //
+    // TEST: During the window, challenges reduce weights and claims are impossible
+    //
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin,
+            time_offset: 150,
+        },
+    )
+    .await
+    .unwrap();
+
+    assert!(send_tx(solana, claim(0)).await.is_err());
+
+    // voter1's weight is still valid
+    assert!(send_tx(solana, challenge(1)).await.is_err());
+
+    solana.advance_by_slots(1).await;
+    send_tx(solana, challenge(0)).await.unwrap();
+    let participant_data: Participant = solana.get_account(participants[0]).await;
+    assert_eq!(participant_data.weight, 99);
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(distribution_data.participant_total_weight, 99 + 99);
+
+    // the weight can't be challenged again, or raised
+    vsr_client::claw_back(solana, voter0, 1000).await;
+    solana.advance_by_slots(1).await;
+    assert!(send_tx(solana, challenge(0)).await.is_err());
+
+    //
+    // TEST: After the window, claims open and challenges are impossible
+    //
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution,
+            admin,
+            time_offset: 250,
+        },
+    )
+    .await
+    .unwrap();
+
+    vsr_client::claw_back(solana, voter1, 0).await;
+    solana.advance_by_slots(1).await;
+    assert!(send_tx(solana, challenge(1)).await.is_err());
+
+    let payer_start_balance = solana.token_account_balance(payer_mint0_account).await;
+    solana.advance_by_slots(1).await;
+    send_tx(solana, claim(0)).await.unwrap();
+    // participant 0 gets half up to rounding, as both have the same weight
+    let claimed = solana.token_account_balance(payer_mint0_account).await - payer_start_balance;
+    assert!(claimed <= distribution_amount / 2 && claimed >= distribution_amount / 2 - 1);
+
+    Ok(())
+}
@@ -0,0 +1,164 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transport::TransportError,
+};
+
+use distribute_by_locked_vote_weight::state::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_vesting() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let payer_mint0_account = context.users[0].token_accounts[0];
+    let target_mint0_account = context.users[1].token_accounts[0];
+    let target_start_balance = solana.token_account_balance(target_mint0_account).await;
+    let distribution_amount = 1000;
+    let voter = &vsr.voters[0];
+
+    //
+    // SETUP: distribution vesting over 200s after a 50s cliff
+    //
+    let accounts = send_tx(
+        solana,
+        CreateDistributionInstruction {
+            index: 0,
+            end_ts: now_ts + 100,
+            weight_ts: now_ts + 100,
+            claim_end_ts: 0,
+            keep_participants: false,
+            min_weight: 0,
+            registrar: vsr.registrar,
+            mint: mint0.pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap();
+    let distribution = accounts.distribution;
+    let vault = accounts.vault;
+
+    // invalid schedules are rejected
+    assert!(send_tx(
+        solana,
+        SetVestingInstruction {
+            distribution,
+            admin,
+            vesting_start_ts: now_ts + 300,
+            vesting_cliff_ts: 0,
+            vesting_end_ts: now_ts + 100,
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        SetVestingInstruction {
+            distribution,
+            admin,
+            vesting_start_ts: now_ts + 100,
+            vesting_cliff_ts: now_ts + 150,
+            vesting_end_ts: now_ts + 300,
+        },
+    )
+    .await
+    .unwrap();
+
+    solana
+        .transfer_token(payer_mint0_account, payer, vault, distribution_amount)
+        .await;
+
+    let participant = send_tx(
+        solana,
+        CreateParticipantInstruction {
+            distribution,
+            voter: voter.pubkey,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .participant;
+    let weight = solana.get_account::<Participant>(participant).await.weight;
+    let share = expected_share(distribution_amount, weight, weight as u128);
+
+    let set_time = |time_offset| SetTimeOffsetInstruction {
+        distribution,
+        admin,
+        time_offset,
+    };
+    let claim = || ClaimInstruction {
+        participant,
+        voter_authority: &voter.authority,
+        target_token: target_mint0_account,
+        payer: payer.pubkey(),
+    };
+
+    //
+    // TEST: Before the cliff nothing is claimable
+    //
+    send_tx(solana, set_time(120)).await.unwrap();
+    send_tx(solana, claim()).await.unwrap();
+    assert_eq!(
+        solana.token_account_balance(target_mint0_account).await,
+        target_start_balance
+    );
+
+    //
+    // TEST: Halfway through, about half is claimable
+    //
+    send_tx(solana, set_time(200)).await.unwrap();
+    solana.advance_by_slots(1).await;
+    send_tx(solana, claim()).await.unwrap();
+
+    let claimed = solana.token_account_balance(target_mint0_account).await - target_start_balance;
+    // the clock may have advanced a bit since now_ts was taken
+    assert!(claimed >= 500 && claimed < 520);
+
+    let participant_data: Participant = solana.get_account(participant).await;
+    assert_eq!(participant_data.rewards.claimed_amount, claimed);
+    assert!(!participant_data.is_claimed());
+
+    //
+    // TEST: After vesting ends, the rest is claimable and the participant closed
+    //
+    send_tx(solana, set_time(1000)).await.unwrap();
+    solana.advance_by_slots(1).await;
+    send_tx(solana, claim()).await.unwrap();
+
+    assert_eq!(
+        solana.token_account_balance(target_mint0_account).await,
+        target_start_balance + share
+    );
+    assert!(solana.get_account_data(participant).await.is_none());
+
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(distribution_data.claim_count, 1);
+
+    Ok(())
+}
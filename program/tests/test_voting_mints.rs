@@ -0,0 +1,147 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, transport::TransportError};
+
+use distribute_by_locked_vote_weight::state::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_voting_mints() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let voter0 = &vsr.voters[0];
+    // -1 is due to rounding down as end_ts > now_ts
+    let weight0 = voter0.locked_amount * 12 / 60 - 1;
+
+    let create_distribution = |index: u64| CreateDistributionInstruction {
+        index,
+        end_ts: now_ts + 100,
+        weight_ts: now_ts + 100,
+        claim_end_ts: 0,
+        keep_participants: false,
+        min_weight: 0,
+        registrar: vsr.registrar,
+        mint: mint0.pubkey,
+        admin,
+        payer,
+    };
+    let set_voting_mints =
+        |distribution: Pubkey, voting_mints: Vec<u8>| SetVotingMintsInstruction {
+            distribution,
+            admin,
+            voting_mints,
+        };
+
+    //
+    // TEST: Without an allowlist, all voting mints count
+    //
+    let distribution = send_tx(solana, create_distribution(0))
+        .await
+        .unwrap()
+        .distribution;
+    let info_event = get_info(solana, distribution, voter0.pubkey).await;
+    assert_eq!(info_event.usable_weight, Some(weight0));
+    let by_mint: Vec<(Pubkey, u64)> = info_event
+        .usable_weight_by_mint
+        .iter()
+        .map(|m| (m.mint, m.amount))
+        .collect();
+    assert_eq!(
+        by_mint,
+        vec![(vsr.voting_mints[0], weight0), (vsr.voting_mints[1], 0)]
+    );
+
+    //
+    // TEST: Only registrar voting mints that are in use can be allowed
+    //
+    let distribution = send_tx(solana, create_distribution(1))
+        .await
+        .unwrap()
+        .distribution;
+    assert!(send_tx(solana, set_voting_mints(distribution, vec![2]))
+        .await
+        .is_err());
+    assert!(send_tx(solana, set_voting_mints(distribution, vec![9]))
+        .await
+        .is_err());
+
+    //
+    // TEST: Deposits in other voting mints don't count
+    //
+    send_tx(solana, set_voting_mints(distribution, vec![1]))
+        .await
+        .unwrap();
+    let info_event = get_info(solana, distribution, voter0.pubkey).await;
+    assert_eq!(info_event.usable_weight, Some(0));
+    assert_eq!(info_event.usable_weight_by_mint.len(), 1);
+    assert_eq!(
+        info_event.usable_weight_by_mint[0].mint,
+        vsr.voting_mints[1]
+    );
+    assert_eq!(info_event.usable_weight_by_mint[0].amount, 0);
+
+    assert!(send_tx(
+        solana,
+        CreateParticipantInstruction {
+            distribution,
+            voter: voter0.pubkey,
+            payer,
+        },
+    )
+    .await
+    .is_err());
+
+    //
+    // TEST: Deposits in allowed voting mints count
+    //
+    let distribution = send_tx(solana, create_distribution(2))
+        .await
+        .unwrap()
+        .distribution;
+    send_tx(solana, set_voting_mints(distribution, vec![0]))
+        .await
+        .unwrap();
+    let participant = send_tx(
+        solana,
+        CreateParticipantInstruction {
+            distribution,
+            voter: voter0.pubkey,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .participant;
+    let participant_data: Participant = solana.get_account(participant).await;
+    assert_eq!(participant_data.weight, weight0);
+
+    //
+    // TEST: The allowlist can't change once participants registered
+    //
+    assert!(send_tx(solana, set_voting_mints(distribution, vec![]))
+        .await
+        .is_err());
+
+    Ok(())
+}
@@ -0,0 +1,205 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transport::TransportError,
+};
+
+use distribute_by_locked_vote_weight::state::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_weight_cap() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let payer_mint0_account = context.users[0].token_accounts[0];
+    let distribution_amount = 1000;
+    let voter0 = &vsr.voters[0];
+    let voter1 = &vsr.voters[1];
+    // -1 is due to rounding down as end_ts > now_ts
+    let weight0 = voter0.locked_amount * 12 / 60 - 1;
+    let weight1 = voter1.locked_amount * 12 / 60 - 1;
+
+    let mut distributions = vec![];
+    for index in 0..2 {
+        let accounts = send_tx(
+            solana,
+            CreateDistributionInstruction {
+                index,
+                end_ts: now_ts + 100,
+                weight_ts: now_ts + 100,
+                claim_end_ts: 0,
+                keep_participants: false,
+                min_weight: 0,
+                registrar: vsr.registrar,
+                mint: mint0.pubkey,
+                admin,
+                payer,
+            },
+        )
+        .await
+        .unwrap();
+        solana
+            .transfer_token(
+                payer_mint0_account,
+                payer,
+                accounts.vault,
+                distribution_amount,
+            )
+            .await;
+        distributions.push(accounts.distribution);
+    }
+
+    //
+    // TEST: An absolute cap limits the registered weight
+    //
+    let cap = weight0 * 3 / 4;
+    send_tx(
+        solana,
+        SetWeightCapInstruction {
+            distribution: distributions[0],
+            admin,
+            max_weight_per_participant: cap,
+            in_bps: false,
+        },
+    )
+    .await
+    .unwrap();
+
+    let info_event = get_info(solana, distributions[0], voter0.pubkey).await;
+    assert_eq!(info_event.usable_weight, Some(cap));
+    assert_eq!(info_event.uncapped_usable_weight, Some(weight0));
+
+    let participant = send_tx(
+        solana,
+        CreateParticipantInstruction {
+            distribution: distributions[0],
+            voter: voter0.pubkey,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .participant;
+    let participant_data: Participant = solana.get_account(participant).await;
+    assert_eq!(participant_data.weight, cap);
+
+    // the cap can't change once participants registered
+    assert!(send_tx(
+        solana,
+        SetWeightCapInstruction {
+            distribution: distributions[0],
+            admin,
+            max_weight_per_participant: 0,
+            in_bps: false,
+        },
+    )
+    .await
+    .is_err());
+
+    //
+    // TEST: A basis points cap limits the share when claiming
+    //
+    // more than 100% is invalid
+    assert!(send_tx(
+        solana,
+        SetWeightCapInstruction {
+            distribution: distributions[1],
+            admin,
+            max_weight_per_participant: 10_001,
+            in_bps: true,
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        SetWeightCapInstruction {
+            distribution: distributions[1],
+            admin,
+            max_weight_per_participant: 5_000,
+            in_bps: true,
+        },
+    )
+    .await
+    .unwrap();
+
+    let mut participants = vec![];
+    for voter in [voter0, voter1] {
+        let participant = send_tx(
+            solana,
+            CreateParticipantInstruction {
+                distribution: distributions[1],
+                voter: voter.pubkey,
+                payer,
+            },
+        )
+        .await
+        .unwrap()
+        .participant;
+        participants.push(participant);
+    }
+    // registration isn't affected
+    let participant_data: Participant = solana.get_account(participants[0]).await;
+    assert_eq!(participant_data.weight, weight0);
+
+    send_tx(
+        solana,
+        SetTimeOffsetInstruction {
+            distribution: distributions[1],
+            admin,
+            time_offset: 1000,
+        },
+    )
+    .await
+    .unwrap();
+
+    let total_weight = (weight0 + weight1) as u128;
+    let claim_weight0 = (total_weight * 5_000 / 10_000) as u64;
+    let info_event = get_info(solana, distributions[1], voter0.pubkey).await;
+    assert_eq!(info_event.registered_weight, Some(weight0));
+    assert_eq!(info_event.claim_weight, Some(claim_weight0));
+
+    let target_token = solana
+        .create_token_account(&voter0.authority.pubkey(), mint0.pubkey)
+        .await;
+    send_tx(
+        solana,
+        ClaimInstruction {
+            participant: participants[0],
+            voter_authority: &voter0.authority,
+            target_token,
+            payer: payer.pubkey(),
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        solana.token_account_balance(target_token).await,
+        expected_share(distribution_amount, claim_weight0, total_weight)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,182 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, transport::TransportError};
+
+use distribute_by_locked_vote_weight::state::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_weight_checkpoints() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let voter0 = &vsr.voters[0];
+    let day = 24 * 60 * 60;
+    let early_ts = now_ts + 100;
+    let late_ts = now_ts + 73 * day;
+
+    let mut distributions = vec![];
+    for (index, weight_ts) in [early_ts, late_ts, early_ts].into_iter().enumerate() {
+        let distribution = send_tx(
+            solana,
+            CreateDistributionInstruction {
+                index: index as u64,
+                end_ts: now_ts + 100,
+                weight_ts,
+                claim_end_ts: 0,
+                keep_participants: false,
+                min_weight: 0,
+                registrar: vsr.registrar,
+                mint: mint0.pubkey,
+                admin,
+                payer,
+            },
+        )
+        .await
+        .unwrap()
+        .distribution;
+        distributions.push(distribution);
+    }
+    let distribution = distributions[2];
+
+    // the weights with only a single weight_ts
+    let early_weight = get_info(solana, distributions[0], voter0.pubkey)
+        .await
+        .usable_weight
+        .unwrap();
+    let late_weight = get_info(solana, distributions[1], voter0.pubkey)
+        .await
+        .usable_weight
+        .unwrap();
+    // the lockup runs down, so there's less weight guaranteed later
+    assert!(late_weight < early_weight);
+
+    //
+    // TEST: Invalid checkpoints are rejected
+    //
+    let invalid = [
+        // factors don't add up to 100%
+        (vec![early_ts, late_ts], vec![5000, 4000]),
+        // mismatched lengths
+        (vec![early_ts, late_ts], vec![10_000]),
+        // too many
+        (vec![early_ts; 5], vec![2000; 5]),
+    ];
+    for (weight_ts, factors_bps) in invalid {
+        assert!(send_tx(
+            solana,
+            SetWeightCheckpointsInstruction {
+                distribution,
+                admin,
+                weight_ts,
+                factors_bps,
+            },
+        )
+        .await
+        .is_err());
+    }
+    // before the end of registration
+    assert!(send_tx(
+        solana,
+        SetWeightCheckpointsInstruction {
+            distribution,
+            admin,
+            weight_ts: vec![now_ts + 50, late_ts],
+            factors_bps: vec![5000, 5000],
+        },
+    )
+    .await
+    .is_err());
+
+    //
+    // TEST: The weight is the weighted average over the checkpoints
+    //
+    send_tx(
+        solana,
+        SetWeightCheckpointsInstruction {
+            distribution,
+            admin,
+            weight_ts: vec![early_ts, late_ts],
+            factors_bps: vec![2500, 7500],
+        },
+    )
+    .await
+    .unwrap();
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(distribution_data.weight_checkpoints().len(), 2);
+
+    let expected_weight = (early_weight * 2500 + late_weight * 7500) / 10_000;
+    let info_event = get_info(solana, distribution, voter0.pubkey).await;
+    assert_eq!(info_event.usable_weight, Some(expected_weight));
+
+    let participant = send_tx(
+        solana,
+        CreateParticipantInstruction {
+            distribution,
+            voter: voter0.pubkey,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .participant;
+    let participant_data: Participant = solana.get_account(participant).await;
+    assert_eq!(participant_data.weight, expected_weight);
+
+    // the checkpoints can't change once participants registered
+    assert!(send_tx(
+        solana,
+        SetWeightCheckpointsInstruction {
+            distribution,
+            admin,
+            weight_ts: vec![],
+            factors_bps: vec![],
+        },
+    )
+    .await
+    .is_err());
+
+    //
+    // TEST: Moving weight_ts later doesn't make the weights stale
+    //
+    send_tx(
+        solana,
+        UpdateDistributionConfigInstruction {
+            distribution,
+            admin,
+            registration_end_ts: 0,
+            weight_ts: late_ts,
+        },
+    )
+    .await
+    .unwrap();
+    let distribution_data: Distribution = solana.get_account(distribution).await;
+    assert_eq!(distribution_data.weight_ts, late_ts);
+    assert!(!distribution_data.participant_weight_is_stale(&participant_data));
+    assert_eq!(
+        distribution_data.participant_total_weight,
+        expected_weight as u128
+    );
+
+    Ok(())
+}
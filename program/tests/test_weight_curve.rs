@@ -0,0 +1,183 @@
+#![cfg(feature = "test-bpf")]
+
+use program_test::*;
+use solana_program_test::*;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transport::TransportError,
+};
+
+use distribute_by_locked_vote_weight::state::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_weight_curve() -> Result<(), TransportError> {
+    //
+    // SETUP: fake registrar / voter accounts
+    //
+    let mut test_config = TestConfig::default();
+
+    let now_ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let vsr = vsr_client::setup_mock_registrar_and_voters(&mut test_config, now_ts);
+
+    //
+    // SETUP: Start
+    //
+    let context = TestContext::new(test_config).await;
+    let solana = &context.solana.clone();
+
+    let admin = &Keypair::new();
+    let payer = &context.users[0].key;
+    let mint0 = &context.mints[0];
+    let payer_mint0_account = context.users[0].token_accounts[0];
+    let distribution_amount = 1000;
+    let voter0 = &vsr.voters[0];
+    let voter1 = &vsr.voters[1];
+    // -1 is due to rounding down as end_ts > now_ts
+    let locked_weight0 = voter0.locked_amount * 12 / 60 - 1;
+    let locked_weight1 = voter1.locked_amount * 12 / 60 - 1;
+
+    let curves = [WeightCurve::Linear, WeightCurve::Sqrt, WeightCurve::Log2];
+    let mut distributions = vec![];
+    for (index, curve) in curves.iter().enumerate() {
+        let accounts = send_tx(
+            solana,
+            CreateDistributionInstruction {
+                index: index as u64,
+                end_ts: now_ts + 100,
+                weight_ts: now_ts + 100,
+                claim_end_ts: 0,
+                keep_participants: false,
+                min_weight: 0,
+                registrar: vsr.registrar,
+                mint: mint0.pubkey,
+                admin,
+                payer,
+            },
+        )
+        .await
+        .unwrap();
+        solana
+            .transfer_token(
+                payer_mint0_account,
+                payer,
+                accounts.vault,
+                distribution_amount,
+            )
+            .await;
+        send_tx(
+            solana,
+            SetWeightCurveInstruction {
+                distribution: accounts.distribution,
+                admin,
+                weight_curve: *curve as u8,
+            },
+        )
+        .await
+        .unwrap();
+        distributions.push(accounts.distribution);
+    }
+
+    // unknown curves are rejected
+    assert!(send_tx(
+        solana,
+        SetWeightCurveInstruction {
+            distribution: distributions[0],
+            admin,
+            weight_curve: 3,
+        },
+    )
+    .await
+    .is_err());
+
+    //
+    // TEST: Participants register with the transformed weight
+    //
+    let mut targets = vec![];
+    for voter in [voter0, voter1] {
+        targets.push(
+            solana
+                .create_token_account(&voter.authority.pubkey(), mint0.pubkey)
+                .await,
+        );
+    }
+
+    let mut participants = vec![];
+    for (distribution, curve) in distributions.iter().zip(curves) {
+        let mut distribution_participants = vec![];
+        for (voter, locked_weight) in [(voter0, locked_weight0), (voter1, locked_weight1)] {
+            let participant = send_tx(
+                solana,
+                CreateParticipantInstruction {
+                    distribution: *distribution,
+                    voter: voter.pubkey,
+                    payer,
+                },
+            )
+            .await
+            .unwrap()
+            .participant;
+            let participant_data: Participant = solana.get_account(participant).await;
+            assert_eq!(participant_data.weight, curve.apply(locked_weight));
+            distribution_participants.push(participant);
+        }
+        participants.push(distribution_participants);
+
+        let distribution_data: Distribution = solana.get_account(*distribution).await;
+        assert_eq!(
+            distribution_data.participant_total_weight,
+            (curve.apply(locked_weight0) + curve.apply(locked_weight1)) as u128
+        );
+    }
+
+    // the curve can't change once participants registered
+    assert!(send_tx(
+        solana,
+        SetWeightCurveInstruction {
+            distribution: distributions[1],
+            admin,
+            weight_curve: WeightCurve::Linear as u8,
+        },
+    )
+    .await
+    .is_err());
+
+    //
+    // TEST: Sublinear curves move payouts towards smaller holders
+    //
+    let mut claimed1 = vec![];
+    for (distribution, distribution_participants) in distributions.iter().zip(&participants) {
+        send_tx(
+            solana,
+            SetTimeOffsetInstruction {
+                distribution: *distribution,
+                admin,
+                time_offset: 1000,
+            },
+        )
+        .await
+        .unwrap();
+
+        let before = solana.token_account_balance(targets[1]).await;
+        send_tx(
+            solana,
+            ClaimInstruction {
+                participant: distribution_participants[1],
+                voter_authority: &voter1.authority,
+                target_token: targets[1],
+                payer: payer.pubkey(),
+            },
+        )
+        .await
+        .unwrap();
+        claimed1.push(solana.token_account_balance(targets[1]).await - before);
+    }
+    assert!(claimed1[0] < claimed1[1]);
+    assert!(claimed1[1] < claimed1[2]);
+
+    Ok(())
+}